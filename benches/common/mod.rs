@@ -0,0 +1,255 @@
+//! Maps under benchmark behind a common interface. All of them use the same hasher, so the numbers reflect the
+//! ordering structures rather than hash function speed.
+
+// Not every benchmark binary uses every operation
+#![allow(dead_code)]
+
+use hashlink::LinkedHashMap;
+use indexmap::IndexMap;
+use linked_map::{AccessOrderedMap, DefaultHashBuilder, LinkedMap, OrderPolicy};
+use lru::LruCache;
+
+pub const SIZES: [usize; 3] = [100, 1_000, 10_000];
+
+/// Maximum number of lookups, moves or removals per benchmark iteration
+pub const OPS: usize = 1_000;
+
+/// Small xorshift generator, so inputs are reproducible
+pub struct Rng(pub u64);
+
+impl Rng {
+    pub fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+/// `n` distinct keys in scattered order
+pub fn keys(n: usize) -> Vec<u64> {
+    (0..n as u64)
+        .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+        .collect()
+}
+
+/// Up to [OPS] distinct keys out of `keys` in random order
+pub fn queries(keys: &[u64]) -> Vec<u64> {
+    let mut rng = Rng(0x2545_F491_4F6C_DD1D);
+    let mut shuffled = keys.to_vec();
+    for i in (1..shuffled.len()).rev() {
+        shuffled.swap(i, rng.below(i as u64 + 1) as usize);
+    }
+    shuffled.truncate(OPS);
+    shuffled
+}
+
+/// Ordered map operations compared across implementations
+pub trait Subject: Sized {
+    const NAME: &'static str;
+
+    /// Build a map by inserting `keys` in order, with each key as its own value
+    fn build(keys: &[u64]) -> Self;
+
+    fn lookup(&self, k: &u64) -> Option<&u64>;
+
+    fn move_to_front(&mut self, k: &u64);
+
+    fn remove(&mut self, k: &u64) -> Option<u64>;
+
+    /// Iterate all entries in order
+    fn sum(&self) -> u64;
+}
+
+impl Subject for LinkedMap<u64, u64> {
+    const NAME: &'static str = "linked_map";
+
+    fn build(keys: &[u64]) -> Self {
+        let mut map = Self::new();
+        for k in keys {
+            map.append(*k, *k);
+        }
+        map
+    }
+
+    fn lookup(&self, k: &u64) -> Option<&u64> {
+        self.get(k)
+    }
+
+    fn move_to_front(&mut self, k: &u64) {
+        if let Some(mut c) = self.cursor_at_mut(k) {
+            c.move_to_front();
+        }
+    }
+
+    fn remove(&mut self, k: &u64) -> Option<u64> {
+        LinkedMap::remove(self, k)
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(|(_, v)| v).sum()
+    }
+}
+
+impl Subject for IndexMap<u64, u64, DefaultHashBuilder> {
+    const NAME: &'static str = "indexmap";
+
+    fn build(keys: &[u64]) -> Self {
+        let mut map = Self::default();
+        for k in keys {
+            map.insert(*k, *k);
+        }
+        map
+    }
+
+    fn lookup(&self, k: &u64) -> Option<&u64> {
+        self.get(k)
+    }
+
+    /// O(n), as all entries before it are shifted
+    fn move_to_front(&mut self, k: &u64) {
+        if let Some(i) = self.get_index_of(k) {
+            self.move_index(i, 0);
+        }
+    }
+
+    /// Order preserving, so O(n) like [move_to_front](Subject::move_to_front)
+    fn remove(&mut self, k: &u64) -> Option<u64> {
+        self.shift_remove(k)
+    }
+
+    fn sum(&self) -> u64 {
+        self.values().sum()
+    }
+}
+
+impl Subject for LinkedHashMap<u64, u64, DefaultHashBuilder> {
+    const NAME: &'static str = "hashlink";
+
+    fn build(keys: &[u64]) -> Self {
+        let mut map = Self::default();
+        for k in keys {
+            map.insert(*k, *k);
+        }
+        map
+    }
+
+    fn lookup(&self, k: &u64) -> Option<&u64> {
+        self.get(k)
+    }
+
+    fn move_to_front(&mut self, k: &u64) {
+        self.to_front(k);
+    }
+
+    fn remove(&mut self, k: &u64) -> Option<u64> {
+        LinkedHashMap::remove(self, k)
+    }
+
+    fn sum(&self) -> u64 {
+        self.values().sum()
+    }
+}
+
+impl Subject for LruCache<u64, u64, DefaultHashBuilder> {
+    const NAME: &'static str = "lru";
+
+    fn build(keys: &[u64]) -> Self {
+        let mut map = Self::unbounded_with_hasher(Default::default());
+        for k in keys {
+            map.put(*k, *k);
+        }
+        map
+    }
+
+    fn lookup(&self, k: &u64) -> Option<&u64> {
+        self.peek(k)
+    }
+
+    fn move_to_front(&mut self, k: &u64) {
+        self.promote(k);
+    }
+
+    fn remove(&mut self, k: &u64) -> Option<u64> {
+        self.pop(k)
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(|(_, v)| v).sum()
+    }
+}
+
+/// Bounded cache, that moves entries to the most recently used end on access and evicts the least recently used
+/// entry, when full
+pub trait Cache {
+    const NAME: &'static str;
+
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Look up `k`, inserting it on a miss. Returns, if it was a hit.
+    fn access(&mut self, k: u64) -> bool;
+}
+
+/// [AccessOrderedMap] with its capacity
+pub struct LinkedMapCache(AccessOrderedMap<u64, u64>, usize);
+
+impl Cache for LinkedMapCache {
+    const NAME: &'static str = "linked_map";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self(AccessOrderedMap::new(OrderPolicy::AccessToBack), capacity)
+    }
+
+    fn access(&mut self, k: u64) -> bool {
+        if self.0.get(&k).is_some() {
+            return true;
+        }
+        self.0.insert(k, k);
+        if self.0.len() > self.1 {
+            self.0.as_map_mut().cursor_mut().remove();
+        }
+        false
+    }
+}
+
+/// [LinkedHashMap] with its capacity
+pub struct HashlinkCache(LinkedHashMap<u64, u64, DefaultHashBuilder>, usize);
+
+impl Cache for HashlinkCache {
+    const NAME: &'static str = "hashlink";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self(LinkedHashMap::default(), capacity)
+    }
+
+    fn access(&mut self, k: u64) -> bool {
+        if self.0.to_back(&k).is_some() {
+            return true;
+        }
+        self.0.insert(k, k);
+        if self.0.len() > self.1 {
+            self.0.pop_front();
+        }
+        false
+    }
+}
+
+impl Cache for LruCache<u64, u64, DefaultHashBuilder> {
+    const NAME: &'static str = "lru";
+
+    fn with_capacity(capacity: usize) -> Self {
+        Self::with_hasher(capacity.try_into().unwrap(), Default::default())
+    }
+
+    fn access(&mut self, k: u64) -> bool {
+        if self.get(&k).is_some() {
+            return true;
+        }
+        self.put(k, k);
+        false
+    }
+}
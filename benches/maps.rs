@@ -0,0 +1,160 @@
+//! Timings of common operations on [LinkedMap] compared to other ordered maps.
+//!
+//! Run with `cargo bench --bench maps`. Memory usage is reported separately by `cargo bench --bench memory`.
+
+mod common;
+
+use common::{keys, queries, Cache, HashlinkCache, LinkedMapCache, Rng, Subject, OPS, SIZES};
+use criterion::measurement::WallTime;
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkGroup, BenchmarkId, Criterion,
+    Throughput,
+};
+use hashlink::LinkedHashMap;
+use indexmap::IndexMap;
+use linked_map::{DefaultHashBuilder, LinkedMap};
+use lru::LruCache;
+
+type Group<'a> = BenchmarkGroup<'a, WallTime>;
+
+/// Run `bench` for every map implementation and size
+macro_rules! for_each_subject {
+    ($c:expr, $name:literal, $bench:ident) => {{
+        let mut group = $c.benchmark_group($name);
+        for n in SIZES {
+            $bench::<LinkedMap<u64, u64>>(&mut group, n);
+            $bench::<IndexMap<u64, u64, DefaultHashBuilder>>(&mut group, n);
+            $bench::<LinkedHashMap<u64, u64, DefaultHashBuilder>>(&mut group, n);
+            $bench::<LruCache<u64, u64, DefaultHashBuilder>>(&mut group, n);
+        }
+        group.finish();
+    }};
+}
+
+fn insert<M: Subject>(group: &mut Group, n: usize) {
+    let keys = keys(n);
+    group.throughput(Throughput::Elements(n as u64));
+    group.bench_function(BenchmarkId::new(M::NAME, n), |b| {
+        b.iter_with_large_drop(|| M::build(black_box(&keys)))
+    });
+}
+
+fn lookup<M: Subject>(group: &mut Group, n: usize) {
+    let keys = keys(n);
+    let queries = queries(&keys);
+    let map = M::build(&keys);
+    group.throughput(Throughput::Elements(queries.len() as u64));
+    group.bench_function(BenchmarkId::new(M::NAME, n), |b| {
+        b.iter(|| {
+            for k in &queries {
+                black_box(map.lookup(k));
+            }
+        })
+    });
+}
+
+fn move_to_front<M: Subject>(group: &mut Group, n: usize) {
+    let keys = keys(n);
+    let queries = queries(&keys);
+    let mut map = M::build(&keys);
+    group.throughput(Throughput::Elements(queries.len() as u64));
+    group.bench_function(BenchmarkId::new(M::NAME, n), |b| {
+        b.iter(|| {
+            for k in &queries {
+                map.move_to_front(k);
+            }
+        })
+    });
+}
+
+fn remove<M: Subject>(group: &mut Group, n: usize) {
+    let keys = keys(n);
+    let queries = queries(&keys);
+    group.throughput(Throughput::Elements(queries.len() as u64));
+    group.bench_function(BenchmarkId::new(M::NAME, n), |b| {
+        b.iter_batched_ref(
+            || M::build(&keys),
+            |map| {
+                for k in &queries {
+                    black_box(map.remove(k));
+                }
+            },
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn iterate<M: Subject>(group: &mut Group, n: usize) {
+    let map = M::build(&keys(n));
+    group.throughput(Throughput::Elements(n as u64));
+    group.bench_function(BenchmarkId::new(M::NAME, n), |b| {
+        b.iter(|| black_box(map.sum()))
+    });
+}
+
+/// Cache with a capacity of `n` entries, where most accesses hit a hot set of half its capacity and the rest are
+/// spread over four times its capacity
+fn lru<C: Cache>(group: &mut Group, n: usize) {
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let stream: Vec<_> = (0..OPS * 10)
+        .map(|_| match rng.below(10) {
+            0 | 1 => rng.below(n as u64 * 4),
+            _ => rng.below((n as u64 / 2).max(1)),
+        })
+        .collect();
+
+    let mut cache = C::with_capacity(n);
+    for k in &stream {
+        cache.access(*k);
+    }
+    group.throughput(Throughput::Elements(stream.len() as u64));
+    group.bench_function(BenchmarkId::new(C::NAME, n), |b| {
+        b.iter(|| {
+            for k in &stream {
+                black_box(cache.access(*k));
+            }
+        })
+    });
+}
+
+fn bench_insert(c: &mut Criterion) {
+    for_each_subject!(c, "insert", insert);
+}
+
+fn bench_lookup(c: &mut Criterion) {
+    for_each_subject!(c, "lookup", lookup);
+}
+
+fn bench_move_to_front(c: &mut Criterion) {
+    for_each_subject!(c, "move_to_front", move_to_front);
+}
+
+fn bench_remove(c: &mut Criterion) {
+    for_each_subject!(c, "remove", remove);
+}
+
+fn bench_iterate(c: &mut Criterion) {
+    for_each_subject!(c, "iterate", iterate);
+}
+
+/// [IndexMap] has no O(1) way to reorder entries, so it is left out
+fn bench_lru(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lru");
+    for n in SIZES {
+        lru::<LinkedMapCache>(&mut group, n);
+        lru::<HashlinkCache>(&mut group, n);
+        lru::<LruCache<u64, u64, DefaultHashBuilder>>(&mut group, n);
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_insert,
+    bench_lookup,
+    bench_move_to_front,
+    bench_remove,
+    bench_iterate,
+    bench_lru
+);
+criterion_main!(benches);
@@ -0,0 +1,73 @@
+//! Heap memory per entry of [LinkedMap] compared to other ordered maps, measured with a counting allocator.
+//!
+//! Run with `cargo bench --bench memory`. Keys and values are `u64`, so 16 bytes of every entry are payload.
+
+mod common;
+
+use common::{keys, Subject, SIZES};
+use hashlink::LinkedHashMap;
+use indexmap::IndexMap;
+use linked_map::{DefaultHashBuilder, LinkedMap};
+use lru::LruCache;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Tracks the number of bytes currently allocated
+struct Counting;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATED.fetch_add(new_size, Ordering::Relaxed);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Bytes allocated per entry by a map of `n` entries, including spare hash table capacity
+fn bytes_per_entry<M: Subject>(n: usize) -> f64 {
+    let keys = keys(n);
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    let map = M::build(&keys);
+    let after = ALLOCATED.load(Ordering::Relaxed);
+    drop(map);
+    (after - before) as f64 / n as f64
+}
+
+fn report<M: Subject>() {
+    print!("{:<12}", M::NAME);
+    for n in SIZES {
+        print!("{:>12.1}", bytes_per_entry::<M>(n));
+    }
+    println!();
+}
+
+fn main() {
+    // Ignore the arguments passed by `cargo bench`
+    print!("{:<12}", "bytes/entry");
+    for n in SIZES {
+        print!("{:>12}", n);
+    }
+    println!();
+
+    report::<LinkedMap<u64, u64>>();
+    report::<IndexMap<u64, u64, DefaultHashBuilder>>();
+    report::<LinkedHashMap<u64, u64, DefaultHashBuilder>>();
+    report::<LruCache<u64, u64, DefaultHashBuilder>>();
+}
@@ -0,0 +1,299 @@
+//! Navigation, peeking and editing through a long-lived [CursorMut](linked_map::CursorMut) and the immutable
+//! [Cursor](linked_map::Cursor) views it hands out
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use linked_map::RekeyError;
+use linked_map_fuzz::{compare, compare_cursor, Key, Map, Model};
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Next,
+    Previous,
+    ReborrowNext,
+    ToFront,
+    ToBack,
+    ToKey(Key),
+    AdvanceBy(u8),
+    RetreatBy(u8),
+    SeekForward(Key),
+    SeekBackward(Key),
+    PeekNext,
+    PeekPrevious,
+    PeekNth(u8),
+    InsertBefore(Key, u16),
+    InsertAfter(Key, u16),
+    InsertBeforeOrUpdate(Key, u16),
+    InsertAfterOrUpdate(Key, u16),
+    RemoveAndRetreat,
+    RemoveAndAdvance,
+    RemoveNext,
+    RemovePrevious,
+    ReplaceValue(u16),
+    ReplaceKey(Key),
+    SetValue(u16),
+    MoveToFront,
+    MoveToBack,
+    MoveBeforeKey(Key),
+    MoveAfterKey(Key),
+    SwapWithNext,
+    SwapWithPrevious,
+    MakeHead,
+    Save,
+    ClearSaved,
+    Resume,
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    initial: Vec<(Key, u16)>,
+    ops: Vec<Op>,
+}
+
+fuzz_target!(|input: Input| {
+    let mut map = Map::default();
+    let mut model = Model::default();
+    for (k, v) in input.initial {
+        assert_eq!(map.append(k.get(), v), model.append(k.get(), v));
+    }
+    compare(&map, &model);
+
+    let mut c = map.cursor_mut();
+    let entry = |model: &Model, i: usize| model.order.get(i).map(|k| (*k, model.values[k]));
+    let len = |model: &Model| model.order.len();
+
+    for op in input.ops {
+        match op {
+            Op::Next | Op::ReborrowNext => {
+                let moves = model.cursor + 1 < len(&model);
+                let moved = match op {
+                    Op::Next => c.next().map(|(k, v)| (*k, *v)),
+                    _ => c.reborrow().next().map(|(k, v)| (*k, *v)),
+                };
+                if moves {
+                    model.cursor += 1;
+                }
+                assert_eq!(moved, entry(&model, model.cursor).filter(|_| moves));
+            }
+            Op::Previous => {
+                let moves = model.cursor > 0;
+                let moved = c.previous().map(|(k, v)| (*k, *v));
+                if moves {
+                    model.cursor -= 1;
+                }
+                assert_eq!(moved, entry(&model, model.cursor).filter(|_| moves));
+            }
+            Op::ToFront => {
+                c.to_front();
+                model.cursor = 0;
+            }
+            Op::ToBack => {
+                c.to_back();
+                model.cursor = len(&model).saturating_sub(1);
+            }
+            Op::ToKey(k) => {
+                let found = c.to_key(&k.get()).map(|(k, v)| (*k, *v));
+                if let Some(i) = model.position(k.get()) {
+                    model.cursor = i;
+                }
+                assert_eq!(
+                    found,
+                    model.position(k.get()).and_then(|i| entry(&model, i))
+                );
+            }
+            Op::AdvanceBy(n) => {
+                let n = n as usize;
+                let moved = n.min(len(&model).saturating_sub(model.cursor + 1));
+                assert_eq!(c.advance_by(n), if moved == n { Ok(n) } else { Err(moved) });
+                model.cursor += moved;
+            }
+            Op::RetreatBy(n) => {
+                let n = n as usize;
+                let moved = n.min(model.cursor);
+                assert_eq!(c.retreat_by(n), if moved == n { Ok(n) } else { Err(moved) });
+                model.cursor -= moved;
+            }
+            Op::SeekForward(k) => {
+                let found = c.seek_forward(|key, _| *key == k.get());
+                let start = model.cursor;
+                match model.position(k.get()).filter(|i| *i >= start) {
+                    Some(i) => {
+                        model.cursor = i;
+                        assert_eq!(found, Ok(i - start));
+                    }
+                    None => {
+                        model.cursor = len(&model).saturating_sub(1);
+                        assert_eq!(found, Err(model.cursor - start));
+                    }
+                }
+            }
+            Op::SeekBackward(k) => {
+                let found = c.seek_backward(|key, _| *key == k.get());
+                let start = model.cursor;
+                match model.position(k.get()).filter(|i| *i <= start) {
+                    Some(i) => {
+                        model.cursor = i;
+                        assert_eq!(found, Ok(start - i));
+                    }
+                    None => {
+                        model.cursor = 0;
+                        assert_eq!(found, Err(start));
+                    }
+                }
+            }
+            Op::PeekNext => {
+                let peeked = c.as_cursor().peek_next().map(|(k, v)| (*k, *v));
+                assert_eq!(peeked, entry(&model, model.cursor + 1));
+            }
+            Op::PeekPrevious => {
+                let peeked = c.as_cursor().peek_previous().map(|(k, v)| (*k, *v));
+                let expected = model.cursor.checked_sub(1).and_then(|i| entry(&model, i));
+                assert_eq!(peeked, expected);
+            }
+            Op::PeekNth(n) => {
+                let peeked = c.as_cursor().peek_nth(n as usize).map(|(k, v)| (*k, *v));
+                assert_eq!(peeked, entry(&model, model.cursor + n as usize));
+            }
+            Op::InsertBefore(k, v) => assert_eq!(
+                c.insert_before(k.get(), v),
+                model.insert_at_cursor(k.get(), v, false, true)
+            ),
+            Op::InsertAfter(k, v) => assert_eq!(
+                c.insert_after(k.get(), v),
+                model.insert_at_cursor(k.get(), v, true, true)
+            ),
+            Op::InsertBeforeOrUpdate(k, v) => assert_eq!(
+                c.insert_before_or_update(k.get(), v),
+                model.insert_at_cursor(k.get(), v, false, false)
+            ),
+            Op::InsertAfterOrUpdate(k, v) => assert_eq!(
+                c.insert_after_or_update(k.get(), v),
+                model.insert_at_cursor(k.get(), v, true, false)
+            ),
+            Op::RemoveAndRetreat => assert_eq!(c.remove_and_retreat(), model.remove_and_retreat()),
+            Op::RemoveAndAdvance => assert_eq!(c.remove_and_advance(), model.remove_and_advance()),
+            Op::RemoveNext => {
+                let expected = entry(&model, model.cursor + 1);
+                if let Some((k, _)) = expected {
+                    model.remove(k);
+                }
+                assert_eq!(c.remove_next(), expected);
+            }
+            Op::RemovePrevious => {
+                let expected = model.cursor.checked_sub(1).and_then(|i| entry(&model, i));
+                if let Some((k, _)) = expected {
+                    model.remove(k);
+                    model.cursor -= 1;
+                }
+                assert_eq!(c.remove_previous(), expected);
+            }
+            Op::ReplaceValue(v) => {
+                let old = model
+                    .cursor_key()
+                    .map(|k| model.values.insert(k, v).unwrap());
+                assert_eq!(c.replace_value(v), old);
+            }
+            Op::ReplaceKey(new) => {
+                let new = new.get();
+                let expected = match model.cursor_key() {
+                    None => Err(RekeyError::NotFound(new)),
+                    Some(old) if old != new && model.values.contains_key(&new) => {
+                        Err(RekeyError::Occupied(new))
+                    }
+                    Some(old) => {
+                        model.order[model.cursor] = new;
+                        let v = model.values.remove(&old).unwrap();
+                        model.values.insert(new, v);
+                        if model.saved == Some(old) {
+                            model.saved = Some(new);
+                        }
+                        Ok(old)
+                    }
+                };
+                assert_eq!(c.replace_key(new), expected);
+            }
+            Op::SetValue(v) => {
+                if let Some((_, value)) = c.key_value_mut() {
+                    *value = v;
+                }
+                if let Some(k) = model.cursor_key() {
+                    model.values.insert(k, v);
+                }
+            }
+            Op::MoveToFront => {
+                c.move_to_front();
+                if let Some(k) = model.cursor_key() {
+                    model.move_to(k, |_| 0);
+                    model.cursor = 0;
+                }
+            }
+            Op::MoveToBack => {
+                c.move_to_back();
+                if let Some(k) = model.cursor_key() {
+                    model.move_to(k, |m| m.order.len());
+                    model.cursor = len(&model) - 1;
+                }
+            }
+            Op::MoveBeforeKey(anchor) | Op::MoveAfterKey(anchor) => {
+                let after = matches!(op, Op::MoveAfterKey(_));
+                let anchor = anchor.get();
+                let moved = if after {
+                    c.move_after_key(&anchor)
+                } else {
+                    c.move_before_key(&anchor)
+                };
+                match model.cursor_key().zip(model.position(anchor)) {
+                    Some((k, _)) => {
+                        assert!(moved);
+                        if k != anchor {
+                            model.move_to(k, |m| m.position(anchor).unwrap() + after as usize);
+                            model.cursor = model.position(k).unwrap();
+                        }
+                    }
+                    None => assert!(!moved),
+                }
+            }
+            Op::SwapWithNext => {
+                let swaps = model.cursor + 1 < len(&model);
+                assert_eq!(c.swap_with_next(), swaps);
+                if swaps {
+                    model.order.swap(model.cursor, model.cursor + 1);
+                    model.cursor += 1;
+                }
+            }
+            Op::SwapWithPrevious => {
+                let swaps = model.cursor > 0;
+                assert_eq!(c.swap_with_previous(), swaps);
+                if swaps {
+                    model.order.swap(model.cursor, model.cursor - 1);
+                    model.cursor -= 1;
+                }
+            }
+            Op::MakeHead => {
+                c.make_head();
+                model.order.rotate_left(model.cursor);
+                model.cursor = 0;
+            }
+            Op::Save => {
+                c.save();
+                model.saved = model.cursor_key();
+            }
+            Op::ClearSaved => {
+                c.clear_saved();
+                model.saved = None;
+            }
+            Op::Resume => {
+                let resumed = c.resume().map(|(k, _)| *k);
+                assert_eq!(resumed, model.saved);
+                if let Some(saved) = model.saved {
+                    model.cursor = model.position(saved).unwrap();
+                }
+            }
+        }
+        compare_cursor(&c, &model);
+    }
+
+    compare(&map, &model);
+});
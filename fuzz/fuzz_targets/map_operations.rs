@@ -0,0 +1,154 @@
+//! Map level operations, including bulk reordering, splitting and draining
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use linked_map::RekeyError;
+use linked_map_fuzz::{compare, Key, Map, Model};
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Prepend(Key, u16),
+    Append(Key, u16),
+    Remove(Key),
+    MoveBefore(Key, Key),
+    MoveAfter(Key, Key),
+    Swap(Key, Key),
+    Rekey(Key, Key),
+    Reverse,
+    RotateLeft(u8),
+    SortByValue,
+    DrainRange(Key, Key),
+    SplitOffAndPrepend(Key),
+    Save(Key),
+    ClearSaved,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut map = Map::default();
+    let mut model = Model::default();
+
+    for op in ops {
+        match op {
+            Op::Prepend(k, v) => assert_eq!(map.prepend(k.get(), v), model.prepend(k.get(), v)),
+            Op::Append(k, v) => assert_eq!(map.append(k.get(), v), model.append(k.get(), v)),
+            Op::Remove(k) => assert_eq!(map.remove(&k.get()), model.remove(k.get())),
+            Op::MoveBefore(k, anchor) => {
+                let (k, anchor) = (k.get(), anchor.get());
+                let present = model.position(k).is_some() && model.position(anchor).is_some();
+                assert_eq!(map.move_before(&k, &anchor), present);
+                if present && k != anchor {
+                    model.move_to(k, |m| m.position(anchor).unwrap());
+                }
+            }
+            Op::MoveAfter(k, anchor) => {
+                let (k, anchor) = (k.get(), anchor.get());
+                let present = model.position(k).is_some() && model.position(anchor).is_some();
+                assert_eq!(map.move_after(&k, &anchor), present);
+                if present && k != anchor {
+                    model.move_to(k, |m| m.position(anchor).unwrap() + 1);
+                }
+            }
+            Op::Swap(a, b) => match (model.position(a.get()), model.position(b.get())) {
+                (Some(i), Some(j)) => {
+                    assert!(map.swap(&a.get(), &b.get()));
+                    model.order.swap(i, j);
+                }
+                _ => assert!(!map.swap(&a.get(), &b.get())),
+            },
+            Op::Rekey(old, new) => {
+                let (old, new) = (old.get(), new.get());
+                let expected = match model.position(old) {
+                    None => Err(RekeyError::NotFound(new)),
+                    Some(_) if old != new && model.values.contains_key(&new) => {
+                        Err(RekeyError::Occupied(new))
+                    }
+                    Some(i) => {
+                        model.order[i] = new;
+                        let v = model.values.remove(&old).unwrap();
+                        model.values.insert(new, v);
+                        if model.saved == Some(old) {
+                            model.saved = Some(new);
+                        }
+                        Ok(())
+                    }
+                };
+                assert_eq!(map.rekey(&old, new), expected);
+            }
+            Op::Reverse => {
+                map.reverse();
+                model.order.make_contiguous().reverse();
+            }
+            Op::RotateLeft(n) => {
+                let n = n as usize % (model.order.len() + 1);
+                map.rotate_left(n);
+                model.order.rotate_left(n);
+            }
+            Op::SortByValue => {
+                map.sort_by_key(|_, v| *v);
+                let values = &model.values;
+                model.order.make_contiguous().sort_by_key(|k| values[k]);
+            }
+            Op::DrainRange(start, end) => {
+                let drained = map
+                    .drain_range(&start.get(), &end.get())
+                    .map(|d| d.collect::<Vec<_>>());
+                match (model.position(start.get()), model.position(end.get())) {
+                    (Some(i), Some(j)) => {
+                        let keys: Vec<_> = model.order.range(i..j.max(i)).copied().collect();
+                        let expected: Vec<_> = keys
+                            .into_iter()
+                            .map(|k| (k, model.remove(k).unwrap()))
+                            .collect();
+                        assert_eq!(drained, Some(expected));
+                    }
+                    _ => assert!(drained.is_none()),
+                }
+            }
+            Op::SplitOffAndPrepend(k) => {
+                let mut tail = map.split_off(&k.get());
+                match model.position(k.get()) {
+                    Some(i) => {
+                        // The saved node moves along with the tail, but is not taken over when splicing it back
+                        let saved_in_tail =
+                            model.saved.filter(|s| model.position(*s).unwrap() >= i);
+                        let mut tail_model = Model {
+                            order: model.order.split_off(i),
+                            values: model.values.clone(),
+                            saved: saved_in_tail,
+                            ..Model::default()
+                        };
+                        tail_model
+                            .values
+                            .retain(|k, _| tail_model.order.contains(k));
+                        model.values.retain(|k, _| !tail_model.order.contains(k));
+                        if saved_in_tail.is_some() {
+                            model.saved = None;
+                        }
+                        compare(&map, &model);
+                        compare(&tail, &tail_model);
+
+                        for k in tail_model.order.iter().rev() {
+                            model.prepend(*k, tail_model.values[k]);
+                        }
+                    }
+                    None => assert!(tail.is_empty()),
+                }
+                map.prepend_map(&mut tail);
+                assert!(tail.is_empty());
+            }
+            Op::Save(k) => {
+                if let Some(mut c) = map.cursor_at_mut(&k.get()) {
+                    c.save();
+                    model.saved = Some(k.get());
+                }
+            }
+            Op::ClearSaved => {
+                map.clear_saved();
+                model.saved = None;
+            }
+        }
+        compare(&map, &model);
+    }
+});
@@ -0,0 +1,100 @@
+//! Interleavings of saving a node, resuming cursors at it and removing it, either through the map or a cursor
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use linked_map_fuzz::{compare, Key, Map, Model};
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Append(Key, u16),
+    Prepend(Key, u16),
+    Remove(Key),
+    Save(Key),
+    ClearSaved,
+    /// Resume at the saved node and move the bookmark by a number of nodes, negative towards the head
+    ResumeAndShift(i8),
+    ResumeAndRemove,
+    ResumeAndRemoveAdvance,
+    ResumeAndRemoveNeighbours,
+    ResumeImmutable,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut map = Map::default();
+    let mut model = Model::default();
+
+    for op in ops {
+        match op {
+            Op::Append(k, v) => assert_eq!(map.append(k.get(), v), model.append(k.get(), v)),
+            Op::Prepend(k, v) => assert_eq!(map.prepend(k.get(), v), model.prepend(k.get(), v)),
+            Op::Remove(k) => assert_eq!(map.remove(&k.get()), model.remove(k.get())),
+            Op::Save(k) => {
+                if let Some(mut c) = map.cursor_at_mut(&k.get()) {
+                    c.save();
+                    model.saved = Some(k.get());
+                }
+            }
+            Op::ClearSaved => {
+                map.clear_saved();
+                model.saved = None;
+            }
+            Op::ResumeAndShift(n) => match map.resume_mut() {
+                Some(mut c) => {
+                    model.cursor = model.position(model.saved.unwrap()).unwrap();
+                    if n >= 0 {
+                        let moved = (n as usize).min(model.order.len() - model.cursor - 1);
+                        assert_eq!(c.advance_by(n as usize).unwrap_or_else(|m| m), moved);
+                        model.cursor += moved;
+                    } else {
+                        let n = n.unsigned_abs() as usize;
+                        let moved = n.min(model.cursor);
+                        assert_eq!(c.retreat_by(n).unwrap_or_else(|m| m), moved);
+                        model.cursor -= moved;
+                    }
+                    c.save();
+                    model.saved = model.cursor_key();
+                }
+                None => assert!(model.saved.is_none()),
+            },
+            Op::ResumeAndRemove | Op::ResumeAndRemoveAdvance => match map.resume_mut() {
+                Some(mut c) => {
+                    model.cursor = model.position(model.saved.unwrap()).unwrap();
+                    if matches!(op, Op::ResumeAndRemove) {
+                        assert_eq!(c.remove(), model.remove_and_retreat());
+                    } else {
+                        assert_eq!(c.remove_and_advance(), model.remove_and_advance());
+                    }
+                    assert!(c.resume().is_none());
+                    assert_eq!(c.key().copied(), model.cursor_key());
+                }
+                None => assert!(model.saved.is_none()),
+            },
+            Op::ResumeAndRemoveNeighbours => match map.resume_mut() {
+                Some(mut c) => {
+                    let i = model.position(model.saved.unwrap()).unwrap();
+                    let next = model.order.get(i + 1).copied();
+                    let previous = i.checked_sub(1).map(|i| model.order[i]);
+                    assert_eq!(c.remove_next().map(|(k, _)| k), next);
+                    assert_eq!(c.remove_previous().map(|(k, _)| k), previous);
+                    next.map(|k| model.remove(k));
+                    previous.map(|k| model.remove(k));
+
+                    // The saved node itself is untouched
+                    assert_eq!(c.resume().map(|(k, _)| *k), model.saved);
+                }
+                None => assert!(model.saved.is_none()),
+            },
+            Op::ResumeImmutable => {
+                let mut c = map.cursor();
+                assert_eq!(c.resume().map(|(k, _)| *k), model.saved);
+                assert_eq!(
+                    c.key().copied(),
+                    model.saved.or(model.order.front().copied())
+                );
+            }
+        }
+        compare(&map, &model);
+    }
+});
@@ -0,0 +1,23 @@
+//! Shared helpers for the fuzz targets. Each target decodes the fuzzer input into a sequence of operations, applies
+//! them to both a [LinkedMap] and the [Model] and compares the two after every step.
+
+use arbitrary::Arbitrary;
+use linked_map::LinkedMap;
+use std::collections::hash_map::RandomState;
+
+/// Key drawn from a small domain, so operations frequently hit existing entries
+#[derive(Arbitrary, Clone, Copy, Debug)]
+pub struct Key(u8);
+
+impl Key {
+    pub fn get(self) -> u8 {
+        self.0 % 32
+    }
+}
+
+pub type Map = LinkedMap<u8, u16, RandomState>;
+
+/// Reference implementation of the map, shared with the unit tests of the crate
+pub type Model = linked_map::test_support::Model<u8, u16>;
+
+pub use linked_map::test_support::{compare, compare_cursor};
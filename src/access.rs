@@ -0,0 +1,320 @@
+use std::{
+    hash::{BuildHasher, Hash},
+    ptr::NonNull,
+};
+
+use crate::{linked_list::list::Node, map::DefaultHashBuilder, Cursor, CursorMut, LinkedMap};
+
+/// Determines how reads and inserts affect the order of entries in an [AccessOrderedMap]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderPolicy {
+    /// Entries keep the order they were inserted in. Reads and updates of existing keys do not reorder entries.
+    #[default]
+    Insertion,
+
+    /// New entries are inserted at the front and every access moves the entry to the front
+    AccessToFront,
+
+    /// New entries are inserted at the back and every access moves the entry to the back, like Java's
+    /// `LinkedHashMap` with `accessOrder = true`
+    AccessToBack,
+}
+
+/// [LinkedMap] wrapper, that reorders entries on access according to an [OrderPolicy].
+///
+/// Reads through [get](AccessOrderedMap::get), [get_mut](AccessOrderedMap::get_mut) and
+/// [cursor_at](AccessOrderedMap::cursor_at) move the entry to the end selected by the policy.
+/// [peek](AccessOrderedMap::peek) and [peek_mut](AccessOrderedMap::peek_mut) read without reordering.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::{AccessOrderedMap, OrderPolicy};
+///
+/// let mut lru = AccessOrderedMap::new(OrderPolicy::AccessToBack);
+/// lru.insert(1, "a");
+/// lru.insert(2, "b");
+/// lru.insert(3, "c");
+///
+/// lru.get(&1);
+/// lru.peek(&2);
+/// assert_eq!(lru.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+///
+/// // Evict the least recently used entry
+/// assert_eq!(lru.as_map_mut().cursor_mut().remove(), Some((2, "b")));
+/// ```
+pub struct AccessOrderedMap<K, V, S = DefaultHashBuilder> {
+    /// Underlying map storing the entries
+    inner: LinkedMap<K, V, S>,
+
+    /// How accesses affect entry order
+    policy: OrderPolicy,
+}
+
+impl<K, V> AccessOrderedMap<K, V, DefaultHashBuilder> {
+    /// Create a new empty [AccessOrderedMap] with the given ordering policy
+    #[inline]
+    pub fn new(policy: OrderPolicy) -> Self {
+        Self::with_hasher(policy, Default::default())
+    }
+}
+
+impl<K, V, S> AccessOrderedMap<K, V, S> {
+    /// Create a new empty [AccessOrderedMap] with the given ordering policy, which will use the given hash builder
+    /// to hash keys
+    #[inline]
+    pub const fn with_hasher(policy: OrderPolicy, hash_builder: S) -> Self {
+        Self {
+            inner: LinkedMap::with_hasher(hash_builder),
+            policy,
+        }
+    }
+
+    /// Wrap an existing [LinkedMap], keeping its current order
+    #[inline]
+    pub fn from_map(inner: LinkedMap<K, V, S>, policy: OrderPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    /// Return the ordering policy of the map
+    #[inline]
+    pub fn policy(&self) -> OrderPolicy {
+        self.policy
+    }
+
+    /// Set the ordering policy of the map. Does not reorder any existing entries.
+    #[inline]
+    pub fn set_policy(&mut self, policy: OrderPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the number of elements in the map
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the map contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the underlying [LinkedMap]
+    #[inline]
+    pub fn as_map(&self) -> &LinkedMap<K, V, S> {
+        &self.inner
+    }
+
+    /// Return a mutable reference to the underlying [LinkedMap].
+    ///
+    /// Operations on it do not apply the ordering policy.
+    #[inline]
+    pub fn as_map_mut(&mut self) -> &mut LinkedMap<K, V, S> {
+        &mut self.inner
+    }
+
+    /// Consume the wrapper and return the underlying [LinkedMap]
+    #[inline]
+    pub fn into_inner(self) -> LinkedMap<K, V, S> {
+        self.inner
+    }
+}
+
+impl<K, V, S> AccessOrderedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Insert a key-value pair according to the ordering policy.
+    ///
+    /// New entries are appended, or prepended with [OrderPolicy::AccessToFront]. Updating an existing key counts as
+    /// an access.
+    ///
+    /// If the map did have this key present, the value is updated, and the old value is returned.
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        match self.policy {
+            OrderPolicy::Insertion => match self.inner.get_mut(&k) {
+                Some(old) => Some(std::mem::replace(old, v)),
+                None => self.inner.append(k, v),
+            },
+            OrderPolicy::AccessToFront => self.inner.prepend(k, v),
+            OrderPolicy::AccessToBack => self.inner.append(k, v),
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key and applies the ordering policy to the entry
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{AccessOrderedMap, OrderPolicy};
+    ///
+    /// let mut map = AccessOrderedMap::new(OrderPolicy::AccessToFront);
+    /// map.insert(1, "a");
+    /// map.insert(2, "b");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2]);
+    /// ```
+    #[inline]
+    pub fn get(&mut self, k: &K) -> Option<&V> {
+        self.touch(k).map(|node| unsafe { &(*node.as_ptr()).val })
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key and applies the ordering policy to the
+    /// entry
+    #[inline]
+    pub fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.touch(k)
+            .map(|node| unsafe { &mut (*node.as_ptr()).val })
+    }
+
+    /// Returns a reference to the value corresponding to the key without reordering the entry
+    #[inline]
+    pub fn peek(&self, k: &K) -> Option<&V> {
+        self.inner.get(k)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key without reordering the entry
+    #[inline]
+    pub fn peek_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.inner.get_mut(k)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key. Does not reorder the entry.
+    #[inline]
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.inner.contains_key(k)
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map
+    #[inline]
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.inner.remove(k)
+    }
+
+    /// Create a mutable cursor navigated to the passed key, after applying the ordering policy to the entry.
+    ///
+    /// Returns [None], if the key is not in the map,
+    #[inline]
+    pub fn cursor_at(&mut self, k: &K) -> Option<CursorMut<'_, K, V, S>> {
+        let node = self.touch(k)?;
+        Some(unsafe { CursorMut::new(&mut self.inner, node.as_ptr()) })
+    }
+
+    /// Create a cursor navigated to the passed key without reordering the entry.
+    ///
+    /// Returns [None], if the key is not in the map,
+    #[inline]
+    pub fn peek_cursor_at(&self, k: &K) -> Option<Cursor<'_, K, V, S>> {
+        self.inner.cursor_at(k)
+    }
+
+    /// Create a cursor over the map set to the first entry
+    #[inline]
+    pub fn cursor(&self) -> Cursor<'_, K, V, S> {
+        self.inner.cursor()
+    }
+
+    /// Iterate the entries from head to tail
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter()
+    }
+
+    /// Iterate the entries from tail to head
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter_rev()
+    }
+
+    /// Apply the ordering policy to the entry of the key, if any, and return its node.
+    /// Hashes the key only once.
+    fn touch(&mut self, k: &K) -> Option<NonNull<Node<K, V>>> {
+        let node = *self.inner.map.get(k)?;
+        match self.policy {
+            OrderPolicy::Insertion => return Some(node),
+            OrderPolicy::AccessToFront => self.inner.list.move_to_front(0, node),
+            OrderPolicy::AccessToBack => self.inner.list.move_to_back(0, node),
+        }
+        self.inner.debug_check_invariants();
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessOrderedMap, OrderPolicy};
+    use crate::{
+        linked_list::tests::{gen_tests, steps, Rng},
+        test_support::{compare, Model},
+    };
+
+    gen_tests! {test_access_order}
+    fn test_access_order(seed: u64) {
+        let mut rng = Rng(seed);
+        let policies = [
+            OrderPolicy::Insertion,
+            OrderPolicy::AccessToFront,
+            OrderPolicy::AccessToBack,
+        ];
+        let mut map = AccessOrderedMap::new(OrderPolicy::Insertion);
+        let mut model = Model::default();
+
+        // Apply the policy of the map to an accessed key of the model
+        let touch = |model: &mut Model<u32, u32>, policy, k| {
+            if model.values.contains_key(&k) {
+                match policy {
+                    OrderPolicy::Insertion => (),
+                    OrderPolicy::AccessToFront => model.move_to(k, |_| 0),
+                    OrderPolicy::AccessToBack => model.move_to(k, |m| m.order.len()),
+                }
+            }
+        };
+
+        for _ in 0..steps(2000) {
+            let k = rng.below(32) as u32;
+            let v = rng.next() as u32;
+            let policy = map.policy();
+            match rng.below(9) {
+                0 => map.set_policy(policies[rng.below(3) as usize]),
+                1 | 2 => {
+                    let old = match policy {
+                        OrderPolicy::Insertion if model.values.contains_key(&k) => {
+                            model.values.insert(k, v)
+                        }
+                        OrderPolicy::AccessToFront => model.prepend(k, v),
+                        _ => model.append(k, v),
+                    };
+                    assert_eq!(map.insert(k, v), old);
+                }
+                3 => {
+                    assert_eq!(map.get(&k), model.values.get(&k));
+                    touch(&mut model, policy, k);
+                }
+                4 => {
+                    if let Some(value) = map.get_mut(&k) {
+                        *value = v;
+                        model.values.insert(k, v);
+                    }
+                    touch(&mut model, policy, k);
+                }
+                5 => assert_eq!(map.peek(&k), model.values.get(&k)),
+                6 => {
+                    if let Some(value) = map.peek_mut(&k) {
+                        *value = v;
+                        model.values.insert(k, v);
+                    }
+                }
+                7 => {
+                    let key = map.cursor_at(&k).map(|c| *c.key().unwrap());
+                    assert_eq!(key, model.values.contains_key(&k).then_some(k));
+                    touch(&mut model, policy, k);
+                }
+                _ => assert_eq!(map.remove(&k), model.remove(k)),
+            }
+            compare(map.as_map(), &model);
+        }
+    }
+}
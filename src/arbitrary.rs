@@ -0,0 +1,55 @@
+use std::hash::{BuildHasher, Hash};
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::LinkedMap;
+
+/// Builds the map from a sequence of entries, each either prepended or appended. Repeated keys move their entry,
+/// so the resulting order does not have to match the insertion order.
+///
+/// # Examples
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use linked_map::LinkedMap;
+///
+/// let mut u = Unstructured::new(&[1, 2, 10, 0, 1, 3, 20, 1, 1, 2, 30, 0]);
+/// let map = LinkedMap::<u8, u8>::arbitrary(&mut u).unwrap();
+/// assert_eq!(map.check_invariants(), Ok(()));
+/// ```
+impl<'a, K, V, S> Arbitrary<'a> for LinkedMap<K, V, S>
+where
+    K: Arbitrary<'a> + Eq + Hash + Clone + 'static,
+    V: Arbitrary<'a> + 'static,
+    S: BuildHasher + Default,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut map = Self::default();
+        for entry in u.arbitrary_iter::<(K, V, bool)>()? {
+            insert_entry(&mut map, entry?);
+        }
+        Ok(map)
+    }
+
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        let mut map = Self::default();
+        for entry in u.arbitrary_take_rest_iter::<(K, V, bool)>()? {
+            insert_entry(&mut map, entry?);
+        }
+        Ok(map)
+    }
+}
+
+/// Prepend or append a generated entry
+fn insert_entry<K, V, S>(map: &mut LinkedMap<K, V, S>, (k, v, front): (K, V, bool))
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    if front {
+        map.prepend(k, v);
+    } else {
+        map.append(k, v);
+    }
+}
@@ -0,0 +1,725 @@
+use std::{
+    cmp::Ordering,
+    hash::{BuildHasher, Hash},
+    ptr::{null_mut, NonNull},
+};
+
+use crate::{
+    linked_list::{list::Node, LinkedList},
+    map::{DefaultHashBuilder, LinkedMap},
+    Cursor, InvariantViolation,
+};
+
+/// Marks absent links in [PositionTree]
+const NIL: usize = usize::MAX;
+
+/// Node of a [PositionTree]
+struct TreeNode<K, V> {
+    /// Parent tree node
+    parent: usize,
+
+    /// Left child tree node, covering list nodes before this one
+    left: usize,
+
+    /// Right child tree node, covering list nodes after this one
+    right: usize,
+
+    /// Number of tree nodes in the subtree rooted at this node
+    size: usize,
+
+    /// Random heap priority keeping the tree balanced
+    priority: u64,
+
+    /// Linked list node at this position
+    node: NonNull<Node<K, V>>,
+}
+
+/// Implicit treap over the linked list nodes, ordered by their position in the list.
+///
+/// Tree nodes are stored in an arena and referenced by their slot index. Subtree sizes enable finding the node at a
+/// position in expected O(log n). The tree node of a list node is found by descending from the root and comparing the
+/// order labels of the list nodes, so finding the position of a node is expected O(log n) as well.
+struct PositionTree<K, V> {
+    /// Arena of tree nodes
+    nodes: Vec<TreeNode<K, V>>,
+
+    /// Unused slots in `nodes`
+    free: Vec<usize>,
+
+    /// Slot of the root node
+    root: usize,
+
+    /// State of the xorshift generator for node priorities
+    rng: u64,
+}
+
+impl<K, V> PositionTree<K, V> {
+    /// Create new empty tree
+    const fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: NIL,
+            rng: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Return the number of nodes in the tree
+    #[inline]
+    fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    /// Return the size of the subtree rooted at `t`
+    #[inline]
+    fn size(&self, t: usize) -> usize {
+        if t == NIL {
+            0
+        } else {
+            self.nodes[t].size
+        }
+    }
+
+    /// Allocate a detached tree node for the list node and return its slot
+    fn alloc(&mut self, node: NonNull<Node<K, V>>) -> usize {
+        self.rng ^= self.rng << 13;
+        self.rng ^= self.rng >> 7;
+        self.rng ^= self.rng << 17;
+
+        let tree_node = TreeNode {
+            parent: NIL,
+            left: NIL,
+            right: NIL,
+            size: 1,
+            priority: self.rng,
+            node,
+        };
+        match self.free.pop() {
+            Some(slot) => {
+                self.nodes[slot] = tree_node;
+                slot
+            }
+            None => {
+                self.nodes.push(tree_node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    /// Release a detached slot for reuse
+    #[inline]
+    fn free(&mut self, slot: usize) {
+        self.free.push(slot);
+    }
+
+    /// Recalculate the sizes of `t` and all its ancestors
+    #[inline]
+    fn update_upwards(&mut self, mut t: usize) {
+        while t != NIL {
+            let TreeNode { left, right, .. } = self.nodes[t];
+            self.nodes[t].size = 1 + self.size(left) + self.size(right);
+            t = self.nodes[t].parent;
+        }
+    }
+
+    /// Link `t` as the right or left child of `parent`, or as the root of a new tree, if `parent` is [NIL].
+    /// Returns the root of the tree, if `parent` is [NIL], and `root` otherwise.
+    #[inline]
+    fn link_child(&mut self, root: usize, parent: usize, t: usize, right: bool) -> usize {
+        if t != NIL {
+            self.nodes[t].parent = parent;
+        }
+        match (parent, right) {
+            (NIL, _) => return t,
+            (_, true) => self.nodes[parent].right = t,
+            (_, false) => self.nodes[parent].left = t,
+        }
+        root
+    }
+
+    /// Merge two trees, with all nodes of `a` positioned before all nodes of `b`, and return the new root.
+    ///
+    /// Walks down the right spine of `a` and the left spine of `b` iteratively, so the depth of the tree does not
+    /// matter.
+    fn merge(&mut self, mut a: usize, mut b: usize) -> usize {
+        let mut root = NIL;
+        let mut parent = NIL;
+        let mut right = false;
+        loop {
+            if a == NIL || b == NIL {
+                let rest = if a == NIL { b } else { a };
+                root = self.link_child(root, parent, rest, right);
+                break;
+            }
+            if self.nodes[a].priority > self.nodes[b].priority {
+                root = self.link_child(root, parent, a, right);
+                (parent, right) = (a, true);
+                a = self.nodes[a].right;
+            } else {
+                root = self.link_child(root, parent, b, right);
+                (parent, right) = (b, false);
+                b = self.nodes[b].left;
+            }
+        }
+        self.update_upwards(parent);
+        root
+    }
+
+    /// Split a tree into one with the first `k` nodes and one with the rest and return their roots.
+    ///
+    /// Walks down from `t` iteratively, appending each visited node to the right spine of the first or the left
+    /// spine of the second tree.
+    fn split(&mut self, mut t: usize, mut k: usize) -> (usize, usize) {
+        let (mut a, mut a_last) = (NIL, NIL);
+        let (mut b, mut b_first) = (NIL, NIL);
+        while t != NIL {
+            let left = self.nodes[t].left;
+            if self.size(left) >= k {
+                b = self.link_child(b, b_first, t, false);
+                b_first = t;
+                t = left;
+            } else {
+                k -= self.size(left) + 1;
+                a = self.link_child(a, a_last, t, true);
+                a_last = t;
+                t = self.nodes[t].right;
+            }
+        }
+
+        // Cut the links to the nodes, that went to the other tree
+        if a_last != NIL {
+            self.nodes[a_last].right = NIL;
+        }
+        if b_first != NIL {
+            self.nodes[b_first].left = NIL;
+        }
+        self.update_upwards(a_last);
+        self.update_upwards(b_first);
+        (a, b)
+    }
+
+    /// Set a new root
+    #[inline]
+    fn set_root(&mut self, root: usize) {
+        self.root = root;
+        if root != NIL {
+            self.nodes[root].parent = NIL;
+        }
+    }
+
+    /// Attach a detached slot at position `i`
+    fn attach(&mut self, slot: usize, i: usize) {
+        let (left, right) = self.split(self.root, i);
+        let left = self.merge(left, slot);
+        let root = self.merge(left, right);
+        self.set_root(root);
+    }
+
+    /// Detach the slot at position `i` from the tree, keeping it allocated, and return it
+    fn detach(&mut self, i: usize) -> usize {
+        let (left, right) = self.split(self.root, i);
+        let (slot, right) = self.split(right, 1);
+        let root = self.merge(left, right);
+        self.set_root(root);
+
+        self.nodes[slot].parent = NIL;
+        slot
+    }
+
+    /// Return the position of a list node, that is attached to the tree, by comparing its order label with the ones
+    /// of the list nodes on the way down from the root
+    ///
+    /// # SAFETY
+    ///
+    /// `node` must belong to `list` and be attached to the tree. All list nodes of the tree must belong to `list`.
+    unsafe fn position(&self, list: &LinkedList<K, V>, node: NonNull<Node<K, V>>) -> usize {
+        let mut i = 0;
+        let mut t = self.root;
+        while t != NIL {
+            let TreeNode { left, right, .. } = self.nodes[t];
+            match list.cmp_position(0, node, self.nodes[t].node) {
+                Ordering::Less => t = left,
+                Ordering::Equal => return i + self.size(left),
+                Ordering::Greater => {
+                    i += self.size(left) + 1;
+                    t = right;
+                }
+            }
+        }
+        unreachable!("list node not in position index")
+    }
+
+    /// Return the list node at position `i`, if any
+    fn select(&self, mut i: usize) -> Option<NonNull<Node<K, V>>> {
+        let mut t = self.root;
+        while t != NIL {
+            let left = self.size(self.nodes[t].left);
+            if i < left {
+                t = self.nodes[t].left;
+            } else if i == left {
+                return Some(self.nodes[t].node);
+            } else {
+                i -= left + 1;
+                t = self.nodes[t].right;
+            }
+        }
+        None
+    }
+
+    /// Check the tree against the list nodes in order and return the number of attached nodes.
+    ///
+    /// Walks the tree in order, checking parent links, subtree sizes and that the tree node at each position refers to
+    /// the next of `nodes`.
+    fn check(
+        &self,
+        nodes: &mut impl Iterator<Item = *mut Node<K, V>>,
+    ) -> Result<usize, InvariantViolation> {
+        let mut index = 0;
+        let mut stack = Vec::new();
+        let mut t = self.root;
+        let mut parent = NIL;
+        loop {
+            while t != NIL {
+                if self.nodes[t].parent != parent {
+                    return Err(InvariantViolation::PositionIndex { index });
+                }
+                stack.push(t);
+                parent = t;
+                t = self.nodes[t].left;
+            }
+            let Some(current) = stack.pop() else {
+                return Ok(index);
+            };
+
+            let TreeNode {
+                left,
+                right,
+                size,
+                node,
+                ..
+            } = self.nodes[current];
+            if size != 1 + self.size(left) + self.size(right) || nodes.next() != Some(node.as_ptr())
+            {
+                return Err(InvariantViolation::PositionIndex { index });
+            }
+            index += 1;
+            parent = current;
+            t = right;
+        }
+    }
+}
+
+/// [LinkedMap] wrapper, that maintains an order-statistics index over the linked list for positional access in
+/// O(log n).
+///
+/// The index is an implicit treap over the list nodes. Lookups by key remain O(1), while
+/// [get_index](IndexedLinkedMap::get_index), [position_of](IndexedLinkedMap::position_of),
+/// [cursor_at_index](IndexedLinkedMap::cursor_at_index), [insert_at](IndexedLinkedMap::insert_at) and
+/// [remove_at](IndexedLinkedMap::remove_at) take expected O(log n). [append](IndexedLinkedMap::append) and
+/// [prepend](IndexedLinkedMap::prepend) are O(log n) as well.
+///
+/// The underlying [LinkedMap] is exposed read-only, since reordering it directly would desynchronize the index.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::IndexedLinkedMap;
+///
+/// let mut map = IndexedLinkedMap::new();
+/// map.append("b", 2);
+/// map.prepend("a", 1);
+/// map.insert_at(1, "c", 3);
+///
+/// assert_eq!(map.get_index(1), Some((&"c", &3)));
+/// assert_eq!(map.position_of(&"b"), Some(2));
+/// assert_eq!(map.remove_at(0), Some(("a", 1)));
+/// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["c", "b"]);
+/// ```
+pub struct IndexedLinkedMap<K, V, S = DefaultHashBuilder> {
+    /// Underlying map storing the entries
+    inner: LinkedMap<K, V, S>,
+
+    /// Order-statistics index over the list nodes
+    tree: PositionTree<K, V>,
+}
+
+impl<K, V> IndexedLinkedMap<K, V, DefaultHashBuilder> {
+    /// Create a new empty [IndexedLinkedMap]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<K, V> Default for IndexedLinkedMap<K, V, DefaultHashBuilder> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, S> IndexedLinkedMap<K, V, S> {
+    /// Create a new empty [IndexedLinkedMap], which will use the given hash builder to hash keys
+    #[inline]
+    pub fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            inner: LinkedMap::with_hasher(hash_builder),
+            tree: PositionTree::new(),
+        }
+    }
+
+    /// Returns the number of elements in the map
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the map contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the underlying [LinkedMap]
+    #[inline]
+    pub fn as_map(&self) -> &LinkedMap<K, V, S> {
+        &self.inner
+    }
+
+    /// Consume the wrapper and return the underlying [LinkedMap]
+    #[inline]
+    pub fn into_inner(self) -> LinkedMap<K, V, S> {
+        self.inner
+    }
+}
+
+impl<K, V, S> IndexedLinkedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Wrap an existing [LinkedMap], building the index over its current order in O(n log n)
+    pub fn from_map(inner: LinkedMap<K, V, S>) -> Self {
+        let mut indexed = Self {
+            inner,
+            tree: PositionTree::new(),
+        };
+        let mut next = indexed.inner.list.head(0);
+        while let Some(node) = NonNull::new(next) {
+            let slot = indexed.tree.alloc(node);
+            indexed.tree.attach(slot, indexed.tree.len());
+            next = unsafe { node.as_ref() }.next(0);
+        }
+        indexed.debug_check_invariants();
+        indexed
+    }
+
+    /// Inserts a key-value pair at the end of the map.
+    ///
+    /// If the map did have this key present, the value is updated, the entry is moved to the end and the old value
+    /// is returned.
+    #[inline]
+    pub fn append(&mut self, k: K, v: V) -> Option<V> {
+        match self.inner.map.get(&k) {
+            Some(_) => self.insert_at(self.len() - 1, k, v),
+            None => self.insert_at(self.len(), k, v),
+        }
+    }
+
+    /// Inserts a key-value pair at the start of the map.
+    ///
+    /// If the map did have this key present, the value is updated, the entry is moved to the start and the old value
+    /// is returned.
+    #[inline]
+    pub fn prepend(&mut self, k: K, v: V) -> Option<V> {
+        self.insert_at(0, k, v)
+    }
+
+    /// Insert a key-value pair, so that it ends up at position `i`.
+    ///
+    /// If the map did have this key present, the value is updated, the entry is moved to position `i` and the old
+    /// value is returned.
+    ///
+    /// # Panics
+    ///
+    /// If `i` is greater than `len()` for a new key or not less than `len()` for an existing key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::IndexedLinkedMap;
+    ///
+    /// let mut map = IndexedLinkedMap::new();
+    /// map.insert_at(0, 'a', ());
+    /// map.insert_at(1, 'b', ());
+    /// map.insert_at(1, 'c', ());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<String>(), "acb");
+    ///
+    /// map.insert_at(2, 'a', ());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<String>(), "cba");
+    /// ```
+    pub fn insert_at(&mut self, i: usize, k: K, v: V) -> Option<V> {
+        match self.inner.map.get(&k).copied() {
+            Some(mut node) => {
+                assert!(i < self.len(), "index out of bounds");
+                let old = std::mem::replace(&mut unsafe { node.as_mut() }.val, v);
+                let slot = self
+                    .tree
+                    .detach(unsafe { self.tree.position(&self.inner.list, node) });
+                self.inner.list.remove(node);
+                self.link_at(node, slot, i);
+                self.debug_check_invariants();
+                Some(old)
+            }
+            None => {
+                assert!(i <= self.len(), "index out of bounds");
+                let node = Node::new(k.clone(), v);
+                self.inner.map.insert(k, node);
+                let slot = self.tree.alloc(node);
+                self.link_at(node, slot, i);
+                self.debug_check_invariants();
+                None
+            }
+        }
+    }
+
+    /// Link an unlinked list node and its detached tree slot at position `i`
+    fn link_at(&mut self, node: NonNull<Node<K, V>>, slot: usize, i: usize) {
+        match self.tree.select(i) {
+            Some(before) => unsafe { self.inner.list.insert_before(0, node, before) },
+            None => self.inner.list.append_node(node),
+        }
+        self.tree.attach(slot, i);
+    }
+
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.inner
+            .map
+            .get(k)
+            .copied()
+            .map(|node| self.remove_node(node).1)
+    }
+
+    /// Remove the entry at position `i` and return its key and value, if any
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::IndexedLinkedMap;
+    ///
+    /// let mut map = IndexedLinkedMap::new();
+    /// map.append(1, "a");
+    /// map.append(2, "b");
+    /// assert_eq!(map.remove_at(1), Some((2, "b")));
+    /// assert_eq!(map.remove_at(1), None);
+    /// ```
+    pub fn remove_at(&mut self, i: usize) -> Option<(K, V)> {
+        self.tree.select(i).map(|node| self.remove_node(node))
+    }
+
+    /// Remove a node from the map and free it
+    fn remove_node(&mut self, node: NonNull<Node<K, V>>) -> (K, V) {
+        let slot = self
+            .tree
+            .detach(unsafe { self.tree.position(&self.inner.list, node) });
+        self.tree.free(slot);
+        self.inner.list.remove(node);
+        self.inner.map.remove(&unsafe { node.as_ref() }.key);
+        if self.inner.saved == node.as_ptr() {
+            self.inner.saved = null_mut();
+        }
+        self.debug_check_invariants();
+
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        (node.key, node.val)
+    }
+
+    /// Returns a reference to the value corresponding to the key
+    #[inline]
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.inner.get(k)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key
+    #[inline]
+    pub fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.inner.get_mut(k)
+    }
+
+    /// Returns `true` if the map contains a value for the specified key
+    #[inline]
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.inner.contains_key(k)
+    }
+
+    /// Return the key-value pair at position `i`, if any
+    #[inline]
+    pub fn get_index(&self, i: usize) -> Option<(&K, &V)> {
+        self.tree.select(i).map(|node| {
+            let node = unsafe { node.as_ref() };
+            (&node.key, &node.val)
+        })
+    }
+
+    /// Return the key and a mutable reference to the value at position `i`, if any
+    #[inline]
+    pub fn get_index_mut(&mut self, i: usize) -> Option<(&K, &mut V)> {
+        self.tree.select(i).map(|mut node| {
+            let node = unsafe { node.as_mut() };
+            (&node.key, &mut node.val)
+        })
+    }
+
+    /// Return the position of the key in the list, if present
+    #[inline]
+    pub fn position_of(&self, k: &K) -> Option<usize> {
+        self.inner
+            .map
+            .get(k)
+            .map(|&node| unsafe { self.tree.position(&self.inner.list, node) })
+    }
+
+    /// Create a cursor navigated to position `i`.
+    ///
+    /// Returns [None], if `i` is out of bounds.
+    #[inline]
+    pub fn cursor_at_index(&self, i: usize) -> Option<Cursor<'_, K, V, S>> {
+        self.tree
+            .select(i)
+            .map(|node| unsafe { Cursor::new(&self.inner, node.as_ptr()) })
+    }
+
+    /// Create a cursor navigated to the passed key.
+    ///
+    /// Returns [None], if the key is not in the map.
+    #[inline]
+    pub fn cursor_at(&self, k: &K) -> Option<Cursor<'_, K, V, S>> {
+        self.inner.cursor_at(k)
+    }
+
+    /// Create a cursor over the map set to the first entry
+    #[inline]
+    pub fn cursor(&self) -> Cursor<'_, K, V, S> {
+        self.inner.cursor()
+    }
+
+    /// Iterate the entries from head to tail
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter()
+    }
+
+    /// Iterate the entries from tail to head
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter_rev()
+    }
+
+    /// Validate the internal structure of the map in O(n).
+    ///
+    /// Runs [LinkedMap::check_invariants] and checks that the position index holds exactly the list nodes in list
+    /// order.
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        self.inner.check_invariants()?;
+
+        let mut nodes = std::iter::successors(NonNull::new(self.inner.list.head(0)), |node| {
+            NonNull::new(unsafe { node.as_ref() }.next(0))
+        })
+        .map(NonNull::as_ptr);
+        let attached = self.tree.check(&mut nodes)?;
+        if attached != self.len() || self.tree.nodes.len() - self.tree.free.len() != attached {
+            return Err(InvariantViolation::PositionIndex { index: attached });
+        }
+        Ok(())
+    }
+
+    /// Panic, if the map is structurally invalid. Only checks with the `debug-invariants` feature enabled.
+    #[inline]
+    fn debug_check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(e) = self.check_invariants() {
+            panic!("IndexedLinkedMap invariant violated: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IndexedLinkedMap;
+    use crate::linked_list::tests::{gen_tests, steps, Rng};
+
+    /// Assert the indexed map matches the model of its entries in list order
+    fn compare_indexed(map: &IndexedLinkedMap<u32, u32>, model: &[(u32, u32)]) {
+        if let Err(e) = map.check_invariants() {
+            panic!("invariant violated: {}", e);
+        }
+        let entries: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(entries, model);
+        assert_eq!(map.len(), model.len());
+    }
+
+    gen_tests! {test_indexed_operations}
+    fn test_indexed_operations(seed: u64) {
+        let mut rng = Rng(seed);
+        let mut map = IndexedLinkedMap::new();
+        let mut model: Vec<(u32, u32)> = Vec::new();
+
+        let position = |model: &[(u32, u32)], k| model.iter().position(|(o, _)| *o == k);
+        for _ in 0..steps(2000) {
+            let k = rng.below(32) as u32;
+            let v = rng.next() as u32;
+            let i = rng.below(model.len() as u64 + 1) as usize;
+            match rng.below(8) {
+                0 => {
+                    let old = position(&model, k).map(|j| model.remove(j).1);
+                    model.push((k, v));
+                    assert_eq!(map.append(k, v), old);
+                }
+                1 => {
+                    let old = position(&model, k).map(|j| model.remove(j).1);
+                    model.insert(0, (k, v));
+                    assert_eq!(map.prepend(k, v), old);
+                }
+                2 | 3 => match position(&model, k) {
+                    // Existing keys can only be moved to an index less than the length
+                    Some(j) if i < model.len() => {
+                        let (_, old) = model.remove(j);
+                        model.insert(i, (k, v));
+                        assert_eq!(map.insert_at(i, k, v), Some(old));
+                    }
+                    Some(_) => (),
+                    None => {
+                        model.insert(i, (k, v));
+                        assert_eq!(map.insert_at(i, k, v), None);
+                    }
+                },
+                4 => {
+                    let old = position(&model, k).map(|j| model.remove(j).1);
+                    assert_eq!(map.remove(&k), old);
+                }
+                5 => {
+                    let old = (i < model.len()).then(|| model.remove(i));
+                    assert_eq!(map.remove_at(i), old);
+                }
+                6 => {
+                    let expected = model.get(i).map(|(k, v)| (k, v));
+                    assert_eq!(map.get_index(i), expected);
+                    let key = map.cursor_at_index(i).map(|c| *c.key().unwrap());
+                    assert_eq!(key, model.get(i).map(|(k, _)| *k));
+                    if let Some((_, value)) = map.get_index_mut(i) {
+                        *value = v;
+                        model[i].1 = v;
+                    }
+                }
+                _ => assert_eq!(map.position_of(&k), position(&model, k)),
+            }
+            compare_indexed(&map, &model);
+        }
+
+        for (i, (k, _)) in model.iter().enumerate() {
+            assert_eq!(map.position_of(k), Some(i));
+        }
+    }
+}
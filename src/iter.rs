@@ -1,6 +1,13 @@
-use crate::{Cursor, CursorMut};
+use crate::{
+    linked_list::{list::Node, LinkedList},
+    Cursor, CursorMut,
+};
 use std::hash::{BuildHasher, Hash};
 use std::iter::{FusedIterator, Iterator};
+use std::{
+    marker::PhantomData,
+    ptr::{null_mut, NonNull},
+};
 
 macro_rules! impl_iter {
     ($name:ident, $cursor:ident, $item:ty) => {
@@ -8,26 +15,29 @@ macro_rules! impl_iter {
             ($direction:ident, $method:ident) => {
                 paste::paste! {
                     /// An iterator over the list's key-value pairs
-                    pub struct [<$name $direction>]<'a, K, V, S>
+                    pub struct [<$name $direction>]<'a, K, V, S, const N: usize = 1>
                     where
                         K: Eq + Hash,
                         S: BuildHasher,
                     {
-                        cursor: $cursor<'a, K, V, S>,
+                        cursor: $cursor<'a, K, V, S, N>,
+
+                        /// The node the cursor is at has not been yielded yet
+                        at_start: bool,
                     }
 
-                    impl<'a, K, V, S> [<$name $direction>]<'a, K, V, S>
+                    impl<'a, K, V, S, const N: usize> [<$name $direction>]<'a, K, V, S, N>
                     where
                         K: Eq + Hash + Clone + 'static,
                         V: 'static,
                         S: BuildHasher,
                     {
-                        pub fn new(cursor: $cursor<'a, K, V, S>) -> Self {
-                            Self{ cursor }
+                        pub fn new(cursor: $cursor<'a, K, V, S, N>) -> Self {
+                            Self{ cursor, at_start: true }
                         }
                     }
 
-                    impl<'a, K, V, S> Iterator for [<$name $direction>]<'a, K, V, S>
+                    impl<'a, K, V, S, const N: usize> Iterator for [<$name $direction>]<'a, K, V, S, N>
                     where
                         K: Eq + Hash + Clone + 'static,
                         V: 'static,
@@ -37,7 +47,14 @@ macro_rules! impl_iter {
 
                         #[inline]
                         fn next(&mut self) -> Option<Self::Item> {
-                            self.cursor.$method()
+                            if self.at_start {
+                                self.at_start = false;
+                            } else if self.cursor.$method(1).is_err() {
+                                return None;
+                            }
+
+                            // Every node is yielded only once, as the cursor moves on before the next item
+                            unsafe { self.cursor.item() }
                         }
 
                         #[inline]
@@ -49,7 +66,7 @@ macro_rules! impl_iter {
                         }
                     }
 
-                    impl<'a, K, V, S> FusedIterator for [<$name $direction>]<'a, K, V, S>
+                    impl<'a, K, V, S, const N: usize> FusedIterator for [<$name $direction>]<'a, K, V, S, N>
                     where
                         K: Eq + Hash + Clone + 'static,
                         V: 'static,
@@ -59,10 +76,149 @@ macro_rules! impl_iter {
             };
         }
 
-        impl_iter_direction!(Forward, next);
-        impl_iter_direction!(Backward, previous);
+        impl_iter_direction!(Forward, advance_by);
+        impl_iter_direction!(Backward, retreat_by);
     };
 }
 
 impl_iter!(Iter, Cursor, (&'a K, &'a V));
 impl_iter!(IterMut, CursorMut, (&'a K, &'a mut V));
+
+macro_rules! impl_range {
+    ($name:ident, $value:ty, $to_item:expr) => {
+        /// Double-ended iterator over a contiguous run of the list's key-value pairs
+        pub struct $name<'a, K, V> {
+            /// Next node to yield from the front. Null, if the iterator is exhausted.
+            front: *mut Node<K, V>,
+
+            /// Next node to yield from the back. Null, if the iterator is exhausted.
+            back: *mut Node<K, V>,
+
+            /// Upper bound of the remaining number of items
+            max_len: usize,
+
+            _marker: PhantomData<(&'a K, $value)>,
+        }
+
+        impl<'a, K, V> $name<'a, K, V> {
+            /// Create an iterator over the nodes from `front` to `back` inclusively.
+            ///
+            /// `front` must not come after `back` in the list. Both must be null for an empty iterator.
+            /// `max_len` must not be less than the number of nodes in the run.
+            #[inline]
+            pub(crate) unsafe fn new(
+                front: *mut Node<K, V>,
+                back: *mut Node<K, V>,
+                max_len: usize,
+            ) -> Self {
+                Self {
+                    front,
+                    back,
+                    max_len,
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Advance past `node`, which was just yielded from either end
+            #[inline]
+            fn step(&mut self, node: NonNull<Node<K, V>>) -> (&'a K, $value) {
+                self.max_len -= 1;
+                if self.front == self.back {
+                    self.front = null_mut();
+                    self.back = null_mut();
+                } else if node.as_ptr() == self.front {
+                    self.front = unsafe { node.as_ref() }.next(0);
+                } else {
+                    self.back = unsafe { node.as_ref() }.previous(0);
+                }
+                ($to_item)(node)
+            }
+        }
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = (&'a K, $value);
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                NonNull::new(self.front).map(|node| self.step(node))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.front.is_null() {
+                    (0, Some(0))
+                } else {
+                    (1, Some(self.max_len))
+                }
+            }
+        }
+
+        impl<'a, K, V> DoubleEndedIterator for $name<'a, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                NonNull::new(self.back).map(|node| self.step(node))
+            }
+        }
+
+        impl<'a, K, V> FusedIterator for $name<'a, K, V> {}
+    };
+}
+
+impl_range!(Range, &'a V, |node: NonNull<Node<K, V>>| {
+    let n = unsafe { &*node.as_ptr() };
+    (&n.key, &n.val)
+});
+impl_range!(RangeMut, &'a mut V, |node: NonNull<Node<K, V>>| {
+    let n = unsafe { &mut *node.as_ptr() };
+    (&n.key, &mut n.val)
+});
+
+/// Draining iterator over a run of key-value pairs detached from a [LinkedMap](crate::LinkedMap).
+///
+/// Any pairs not yielded are dropped together with the iterator.
+pub struct Drain<K, V> {
+    /// Detached nodes not yet yielded
+    list: LinkedList<K, V>,
+
+    /// Number of nodes in `list`
+    len: usize,
+}
+
+impl<K, V> Drain<K, V> {
+    /// Create a draining iterator over a detached list of `len` nodes
+    #[inline]
+    pub(crate) fn new(list: LinkedList<K, V>, len: usize) -> Self {
+        Self { list, len }
+    }
+}
+
+impl<K, V> Iterator for Drain<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front().map(|n| {
+            self.len -= 1;
+            (n.key, n.val)
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Drain<K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back().map(|n| {
+            self.len -= 1;
+            (n.key, n.val)
+        })
+    }
+}
+
+impl<K, V> ExactSizeIterator for Drain<K, V> {}
+
+impl<K, V> FusedIterator for Drain<K, V> {}
@@ -1,7 +1,33 @@
 mod linked_list;
-pub use linked_list::{Cursor, CursorMut};
+pub use linked_list::{Cursor, CursorMut, InsertOutcome};
 
 mod iter;
+pub use iter::{Drain, Range, RangeMut};
 
 mod map;
 pub use map::*;
+
+mod sorted;
+pub use sorted::SortedLinkedMap;
+
+mod access;
+pub use access::{AccessOrderedMap, OrderPolicy};
+
+mod indexed;
+pub use indexed::IndexedLinkedMap;
+
+mod set;
+pub use set::{LinkedSet, SetCursor, SetCursorMut};
+
+mod multi;
+pub use multi::{LinkedMultiMap, MultiCursor, MultiCursorMut};
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+
+#[cfg(any(test, feature = "test-support"))]
+#[doc(hidden)]
+pub mod test_support;
@@ -1,114 +1,417 @@
 use hashbrown::hash_map::Entry;
 
-use crate::LinkedMap;
+use crate::{LinkedMap, RekeyError, SpliceError, SplicePolicy};
 
 use paste::paste;
 use std::{
+    cmp::Ordering,
     hash::{BuildHasher, Hash},
     ptr::{null_mut, NonNull},
 };
 
 use super::list::Node;
 
-// TODO: add examples to all of the public ones
-
-/// Implement functionality common to both mutable and immutable cursors
+/// Implement functionality common to both mutable and immutable cursors.
+///
+/// `$peek` is returned by methods borrowing the cursor immutably and `$nav` by methods moving it. `$item` is
+/// yielded by the iterators consuming the cursor.
 macro_rules! impl_common {
-    ($key_value:ty, $iterator:ident) => {
+    ($peek:ty, $nav:ty, $item:ty, $iterator:ident) => {
         /// Helper for getting a reference to the current node
         #[inline]
-        fn current(&self) -> Option<&mut Node<K, V>> {
-            unsafe { self.current.as_mut() }
+        fn current(&self) -> Option<&Node<K, V, N>> {
+            unsafe { self.position().as_ref() }
         }
 
-        /// Navigate to the start of the linked list
+        /// Navigate to the start of the cursor's ordering
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// let mut cursor = map.cursor_at(&2).unwrap();
+        /// cursor.to_front();
+        /// assert_eq!(cursor.key(), Some(&1));
+        /// ```
         pub fn to_front(&mut self) {
-            self.current = self.parent.list.head();
+            self.set_position(self.parent.list.head(self.order));
         }
 
-        /// Navigate to the end of the linked list
+        /// Navigate to the end of the cursor's ordering
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// let mut cursor = map.cursor();
+        /// cursor.to_back();
+        /// assert_eq!(cursor.key(), Some(&3));
+        /// ```
         pub fn to_back(&mut self) {
-            self.current = self.parent.list.tail();
+            self.set_position(self.parent.list.tail(self.order));
         }
 
         /// Returns a reference to the current node's key in the map.
         /// Only returns None, if the current linked list is empty.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let mut map = LinkedMap::new();
+        /// assert_eq!(map.cursor().key(), None);
+        ///
+        /// map.append(1, 'a');
+        /// assert_eq!(map.cursor().key(), Some(&1));
+        /// ```
         pub fn key(&self) -> Option<&K> {
-            self.current().map(|n| &n.key)
+            NonNull::new(self.position()).map(|n| unsafe { &(*n.as_ptr()).key })
         }
 
         /// Return a reference to the current node's key-value pair.
         /// Only returns None, if the list is empty.
-        pub fn key_value(&self) -> Option<$key_value> {
-            Self::map_ptr(self.current)
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// assert_eq!(map.cursor_at(&2).unwrap().key_value(), Some((&2, &'b')));
+        /// ```
+        pub fn key_value(&self) -> Option<$peek> {
+            self.map_ptr(self.position())
         }
 
         /// Return the number of elements in the parent [LinkedMap]
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// assert_eq!(map.cursor().len(), 3);
+        /// ```
         pub fn len(&self) -> usize {
             self.parent.map.len()
         }
 
         /// Return, if the parent [LinkedMap] is empty
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<i32, ()> = LinkedMap::new();
+        /// assert!(map.cursor().is_empty());
+        /// ```
         pub fn is_empty(&self) -> bool {
             self.parent.map.is_empty()
         }
 
         /// Returns the number of elements the parent [LinkedMap] can hold without reallocating
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<i32, ()> = LinkedMap::with_capacity(10);
+        /// assert!(map.cursor().capacity() >= 10);
+        /// ```
         pub fn capacity(&self) -> usize {
             self.parent.map.capacity()
         }
 
         /// Try to advances cursor to the next node and return the key and value of that node
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b')].into_iter().collect();
+        /// let mut cursor = map.cursor();
+        /// assert_eq!(cursor.next(), Some((&2, &'b')));
+        /// assert_eq!(cursor.next(), None);
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// ```
         #[inline]
         #[allow(clippy::should_implement_trait)]
-        pub fn next(&mut self) -> Option<$key_value> {
-            unsafe { self.current.as_ref() }.and_then(|current| {
-                unsafe { current.next().as_mut() }.map(|next| {
-                    self.current = next;
-                    Self::map_non_null(next.into())
-                })
-            })
+        pub fn next(&mut self) -> Option<$nav> {
+            let next = NonNull::new(unsafe { Node::next_of(self.position(), self.order) })?;
+            self.set_position(next.as_ptr());
+            Some(self.map_non_null(next))
         }
 
         /// Try to move cursor to the next previous and return the key and value of that node
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b')].into_iter().collect();
+        /// let mut cursor = map.cursor_at(&2).unwrap();
+        /// assert_eq!(cursor.previous(), Some((&1, &'a')));
+        /// assert_eq!(cursor.previous(), None);
+        /// assert_eq!(cursor.key(), Some(&1));
+        /// ```
         #[inline]
-        pub fn previous(&mut self) -> Option<$key_value> {
-            unsafe { self.current.as_ref() }.and_then(|current| {
-                unsafe { current.previous().as_mut() }.map(|prev| {
-                    self.current = prev;
-                    Self::map_non_null(prev.into())
-                })
-            })
+        pub fn previous(&mut self) -> Option<$nav> {
+            let previous = NonNull::new(unsafe { Node::previous_of(self.position(), self.order) })?;
+            self.set_position(previous.as_ptr());
+            Some(self.map_non_null(previous))
         }
 
         /// Try to navigate to the given key and return its key-value pair.
         /// Returns [None], if no such key found.
-        pub fn to_key(&mut self, key: &K) -> Option<$key_value> {
-            self.parent.map.get(key).map(|n| {
-                self.current = n.as_ptr();
-                Self::map_non_null(*n)
-            })
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let mut cursor = map.cursor();
+        /// assert_eq!(cursor.to_key(&3), Some((&3, &'c')));
+        /// assert_eq!(cursor.to_key(&4), None);
+        /// assert_eq!(cursor.key(), Some(&3));
+        /// ```
+        pub fn to_key(&mut self, key: &K) -> Option<$nav> {
+            let node = *self.parent.map.get(key)?;
+            self.set_position(node.as_ptr());
+            Some(self.map_non_null(node))
         }
 
         /// Get the key and value of the next node (if any), without advancing the cursor
-        pub fn peek_next(&self) -> Option<$key_value> {
-            self.current().and_then(|n| Self::map_ptr(n.next()))
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let cursor = map.cursor_at(&2).unwrap();
+        /// assert_eq!(cursor.peek_next(), Some((&3, &'c')));
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// ```
+        pub fn peek_next(&self) -> Option<$peek> {
+            self.map_ptr(unsafe { Node::next_of(self.position(), self.order) })
         }
 
         /// Get the key and value of the previous node (if any), without advancing the cursor
-        pub fn peek_previous(&self) -> Option<$key_value> {
-            self.current().and_then(|n| Self::map_ptr(n.previous()))
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let cursor = map.cursor_at(&2).unwrap();
+        /// assert_eq!(cursor.peek_previous(), Some((&1, &'a')));
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// ```
+        pub fn peek_previous(&self) -> Option<$peek> {
+            self.map_ptr(unsafe { Node::previous_of(self.position(), self.order) })
         }
 
-        /// Iterate the list towards the tail
-        pub fn iter(self) -> impl Iterator<Item = $key_value> {
+        /// Advance the cursor towards the tail until a node matching `predicate` is found, starting with the current
+        /// node.
+        ///
+        /// Returns `Ok` with the number of nodes moved, if a match was found, leaving the cursor at the matching node.
+        /// Otherwise returns `Err` with the number of nodes moved, leaving the cursor at the tail.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let mut cursor = map.cursor();
+        /// assert_eq!(cursor.seek_forward(|_, v| *v == 'b'), Ok(1));
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// assert_eq!(cursor.seek_forward(|_, v| *v == 'z'), Err(1));
+        /// assert_eq!(cursor.key(), Some(&3));
+        /// ```
+        pub fn seek_forward<F>(&mut self, mut predicate: F) -> Result<usize, usize>
+        where
+            F: FnMut(&K, &V) -> bool,
+        {
+            let mut moved = 0;
+            while let Some(current) = self.current() {
+                if predicate(&current.key, &current.val) {
+                    return Ok(moved);
+                }
+                if current.next(self.order).is_null() {
+                    break;
+                }
+                self.set_position(current.next(self.order));
+                moved += 1;
+            }
+            Err(moved)
+        }
+
+        /// Move the cursor towards the head until a node matching `predicate` is found, starting with the current
+        /// node.
+        ///
+        /// Returns `Ok` with the number of nodes moved, if a match was found, leaving the cursor at the matching node.
+        /// Otherwise returns `Err` with the number of nodes moved, leaving the cursor at the head.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let mut cursor = map.cursor();
+        /// cursor.to_back();
+        /// assert_eq!(cursor.seek_backward(|_, v| *v == 'b'), Ok(1));
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// assert_eq!(cursor.seek_backward(|_, v| *v == 'z'), Err(1));
+        /// assert_eq!(cursor.key(), Some(&1));
+        /// ```
+        pub fn seek_backward<F>(&mut self, mut predicate: F) -> Result<usize, usize>
+        where
+            F: FnMut(&K, &V) -> bool,
+        {
+            let mut moved = 0;
+            while let Some(current) = self.current() {
+                if predicate(&current.key, &current.val) {
+                    return Ok(moved);
+                }
+                if current.previous(self.order).is_null() {
+                    break;
+                }
+                self.set_position(current.previous(self.order));
+                moved += 1;
+            }
+            Err(moved)
+        }
+
+        /// Advance the cursor `n` nodes towards the tail.
+        ///
+        /// Returns `Ok(n)`, if the cursor moved by `n` nodes. Otherwise returns `Err` with the number of nodes moved,
+        /// leaving the cursor at the tail.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let mut cursor = map.cursor();
+        /// assert_eq!(cursor.peek_nth(2), Some((&3, &'c')));
+        /// assert_eq!(cursor.advance_by(1), Ok(1));
+        /// assert_eq!(cursor.advance_by(5), Err(1));
+        /// assert_eq!(cursor.retreat_by(2), Ok(2));
+        /// assert_eq!(cursor.key(), Some(&1));
+        /// ```
+        pub fn advance_by(&mut self, n: usize) -> Result<usize, usize> {
+            for moved in 0..n {
+                match unsafe { Node::next_of(self.position(), self.order) } {
+                    next if !next.is_null() => self.set_position(next),
+                    _ => return Err(moved),
+                }
+            }
+            Ok(n)
+        }
+
+        /// Move the cursor `n` nodes towards the head.
+        ///
+        /// Returns `Ok(n)`, if the cursor moved by `n` nodes. Otherwise returns `Err` with the number of nodes moved,
+        /// leaving the cursor at the head.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let mut cursor = map.cursor_at(&3).unwrap();
+        /// assert_eq!(cursor.retreat_by(1), Ok(1));
+        /// assert_eq!(cursor.retreat_by(5), Err(1));
+        /// assert_eq!(cursor.key(), Some(&1));
+        /// ```
+        pub fn retreat_by(&mut self, n: usize) -> Result<usize, usize> {
+            for moved in 0..n {
+                match unsafe { Node::previous_of(self.position(), self.order) } {
+                    previous if !previous.is_null() => self.set_position(previous),
+                    _ => return Err(moved),
+                }
+            }
+            Ok(n)
+        }
+
+        /// Get the key and value of the node `n` positions towards the tail (if any), without moving the cursor.
+        /// `peek_nth(0)` returns the current node.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+        /// let cursor = map.cursor_at(&2).unwrap();
+        /// assert_eq!(cursor.peek_nth(0), Some((&2, &'b')));
+        /// assert_eq!(cursor.peek_nth(1), Some((&3, &'c')));
+        /// assert_eq!(cursor.peek_nth(2), None);
+        /// ```
+        pub fn peek_nth(&self, n: usize) -> Option<$peek> {
+            let mut node = self.position();
+            for _ in 0..n {
+                node = unsafe { Node::next_of(node, self.order) };
+            }
+            self.map_ptr(node)
+        }
+
+        /// Return the current key-value pair with the lifetime of the parent borrow. Used by iterators consuming
+        /// the cursor.
+        ///
+        /// # SAFETY
+        ///
+        /// For mutable cursors, the pair must not be returned again, while the previously returned one is alive.
+        #[inline]
+        pub(crate) unsafe fn item(&self) -> Option<$item> {
+            NonNull::new(self.position()).map(|n| Self::map_item(n))
+        }
+
+        /// Iterate the cursor's ordering towards the tail
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// let keys: Vec<_> = map.cursor_at(&2).unwrap().iter().map(|(k, _)| *k).collect();
+        /// assert_eq!(keys, [2, 3]);
+        /// ```
+        pub fn iter(self) -> impl Iterator<Item = $item> {
             paste! {
                 crate::iter::[< $iterator Forward>]::new(self)
             }
         }
 
-        /// Iterate the list towards the head
-        pub fn iter_rev(self) -> impl Iterator<Item = $key_value> {
+        /// Iterate the cursor's ordering towards the head
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// let keys: Vec<_> = map.cursor_at(&2).unwrap().iter_rev().map(|(k, _)| *k).collect();
+        /// assert_eq!(keys, [2, 1]);
+        /// ```
+        pub fn iter_rev(self) -> impl Iterator<Item = $item> {
             paste! {
                 crate::iter::[< $iterator Backward>]::new(self)
             }
@@ -118,189 +421,578 @@ macro_rules! impl_common {
         /// key-value pair.
         ///
         /// If no node is currently saved, returns [None].
-        pub fn resume(&mut self) -> Option<$key_value> {
-            unsafe { self.parent.saved.as_mut() }.map(|n| {
-                self.current = n;
-                Self::map_non_null(n.into())
-            })
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::LinkedMap;
+        ///
+        /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+        /// map.cursor_at_mut(&2).unwrap().save();
+        ///
+        /// let mut cursor = map.cursor();
+        /// assert_eq!(cursor.resume(), Some((&2, &())));
+        /// assert_eq!(cursor.key(), Some(&2));
+        /// ```
+        pub fn resume(&mut self) -> Option<$nav> {
+            let saved = NonNull::new(self.parent.saved)?;
+            self.set_position(saved.as_ptr());
+            Some(self.map_non_null(saved))
+        }
+
+        /// Return the index of the ordering the cursor navigates
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use linked_map::{DefaultHashBuilder, LinkedMap};
+        ///
+        /// let map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+        /// assert_eq!(map.cursor().order(), 0);
+        /// assert_eq!(map.cursor_in(1).order(), 1);
+        /// ```
+        pub fn order(&self) -> usize {
+            self.order
         }
     };
 }
 
-/// Enables safe linked list traversal
-pub struct Cursor<'a, K, V, S> {
+/// Enables safe linked list traversal along one ordering of a [LinkedMap]
+pub struct Cursor<'a, K, V, S, const N: usize = 1> {
     /// Parent [LinkedMap]
-    parent: &'a LinkedMap<K, V, S>,
+    parent: &'a LinkedMap<K, V, S, N>,
 
     /// Node the cursor is currently at. Can be null, if parent is empty.
-    current: *mut Node<K, V>,
+    current: *mut Node<K, V, N>,
+
+    /// Ordering the cursor navigates
+    order: usize,
 }
 
-impl<'a, K, V, S> Cursor<'a, K, V, S>
+impl<'a, K, V, S, const N: usize> Cursor<'a, K, V, S, N>
 where
     K: Eq + Hash + Clone + 'static,
     V: 'static,
     S: BuildHasher,
 {
-    impl_common! {(&'a K, &'a V), Iter}
+    impl_common! {(&'a K, &'a V), (&'a K, &'a V), (&'a K, &'a V), Iter}
 
-    /// Create a cursor over the passed list, setting the cursor position to the passed node.
+    /// Create a cursor over the first ordering of the passed list, setting the cursor position to the passed node.
     ///
     /// `position` must belong to the parent and be null, only if the parent is empty.
     #[inline]
-    pub(crate) unsafe fn new(parent: &'a LinkedMap<K, V, S>, position: *mut Node<K, V>) -> Self {
+    pub(crate) unsafe fn new(
+        parent: &'a LinkedMap<K, V, S, N>,
+        position: *mut Node<K, V, N>,
+    ) -> Self {
+        Self::new_in(parent, 0, position)
+    }
+
+    /// Create a cursor over the ordering `order` of the passed list, setting the cursor position to the passed node.
+    ///
+    /// `position` must belong to the parent and be null, only if the parent is empty. `order` must be less than `N`.
+    #[inline]
+    pub(crate) unsafe fn new_in(
+        parent: &'a LinkedMap<K, V, S, N>,
+        order: usize,
+        position: *mut Node<K, V, N>,
+    ) -> Self {
         Self {
             parent,
             current: position,
+            order,
         }
     }
 
     /// Return a reference to the current node's value.
     /// Only returns None, if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = [(1, 'a')].into_iter().collect();
+    /// assert_eq!(map.cursor().value(), Some(&'a'));
+    /// ```
     pub fn value(&self) -> Option<&V> {
         self.current().map(|n| &n.val)
     }
 
     /// Map pointer to key-value reference pair
     #[inline]
-    fn map_ptr(node: *mut Node<K, V>) -> Option<(&'a K, &'a V)> {
-        unsafe { node.as_ref() }.map(|n| (&n.key, &n.val))
+    fn map_ptr(&self, node: *mut Node<K, V, N>) -> Option<(&'a K, &'a V)> {
+        NonNull::new(node).map(Self::map_item)
     }
 
     /// Map [NonNull] pointer to key-value reference pair
     #[inline]
-    fn map_non_null(node: NonNull<Node<K, V>>) -> (&'a K, &'a V) {
-        let n = unsafe { node.as_ref() };
-        (&n.key, &n.val)
+    fn map_non_null(&mut self, node: NonNull<Node<K, V, N>>) -> (&'a K, &'a V) {
+        Self::map_item(node)
+    }
+
+    /// Map [NonNull] pointer to key-value reference pair with the lifetime of the parent borrow
+    #[inline]
+    fn map_item(node: NonNull<Node<K, V, N>>) -> (&'a K, &'a V) {
+        let n = node.as_ptr();
+        unsafe { (&(*n).key, &(*n).val) }
+    }
+
+    /// Helper for reading the position of the cursor
+    #[inline]
+    fn position(&self) -> *mut Node<K, V, N> {
+        self.current
+    }
+
+    /// Helper for setting the position of the cursor
+    #[inline]
+    fn set_position(&mut self, node: *mut Node<K, V, N>) {
+        self.current = node;
     }
 }
 
-impl<'a, K, V, S> Clone for Cursor<'a, K, V, S> {
+impl<'a, K, V, S, const N: usize> Clone for Cursor<'a, K, V, S, N> {
     fn clone(&self) -> Self {
         Self {
             parent: self.parent,
             current: self.current,
+            order: self.order,
+        }
+    }
+}
+
+impl<'a, K, V, S, const N: usize> PartialEq for Cursor<'a, K, V, S, N> {
+    /// Cursors are equal, if they are over the same ordering of the same map and at the same node
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.parent, other.parent)
+            && self.order == other.order
+            && self.current == other.current
+    }
+}
+
+impl<'a, K, V, S, const N: usize> PartialOrd for Cursor<'a, K, V, S, N> {
+    /// Compare the positions of two cursors over the same ordering of the same map in O(1).
+    ///
+    /// Returns [None], if the cursors are over different maps or orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// assert!(map.cursor_at(&1).unwrap() < map.cursor_at(&2).unwrap());
+    ///
+    /// let other = map.clone();
+    /// assert_eq!(map.cursor().partial_cmp(&other.cursor()), None);
+    /// ```
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if !std::ptr::eq(self.parent, other.parent) || self.order != other.order {
+            return None;
+        }
+        match (NonNull::new(self.current), NonNull::new(other.current)) {
+            (Some(a), Some(b)) => Some(unsafe { self.parent.list.cmp_position(self.order, a, b) }),
+            _ => Some(Ordering::Equal), // Both are null on an empty map
         }
     }
 }
 
-/// Enables safe linked list traversal and mutation
-pub struct CursorMut<'a, K, V, S> {
+/// Enables safe linked list traversal and mutation along one ordering of a [LinkedMap]
+///
+/// Methods rearranging nodes only affect the cursor's ordering. Nodes inserted through the cursor are linked next to
+/// the current node in the cursor's ordering and at the end of all other orderings.
+pub struct CursorMut<'a, K, V, S, const N: usize = 1> {
     /// Parent [LinkedMap]
-    parent: &'a mut LinkedMap<K, V, S>,
+    parent: &'a mut LinkedMap<K, V, S, N>,
 
     /// Node the cursor is currently at. Can be null, if parent is empty.
-    current: *mut Node<K, V>,
+    current: Position<'a, K, V, N>,
+
+    /// Ordering the cursor navigates and rearranges
+    order: usize,
 }
 
-impl<'a, K, V, S> CursorMut<'a, K, V, S>
+/// Outcome of inserting a key-value pair through a [CursorMut]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InsertOutcome<V> {
+    /// The key was not in the map and a new node was inserted
+    Inserted,
+
+    /// The key was already in the map. Its node was moved next to the cursor and its value replaced.
+    Moved {
+        /// Value the key had before the insert
+        old_value: V,
+    },
+
+    /// The key was already in the map and only its value was replaced. The node was not moved, as it either is the
+    /// current node or moving was not requested.
+    UpdatedInPlace {
+        /// Value the key had before the insert
+        old_value: V,
+    },
+}
+
+/// Storage of the node a [CursorMut] is at
+enum Position<'a, K, V, const N: usize> {
+    /// Position owned by the cursor
+    Owned(*mut Node<K, V, N>),
+
+    /// Position shared with the cursor this one was reborrowed from
+    Borrowed(&'a mut *mut Node<K, V, N>),
+}
+
+impl<'a, K, V, S, const N: usize> CursorMut<'a, K, V, S, N>
 where
     K: Eq + Hash + Clone + 'static,
     V: 'static,
     S: BuildHasher,
 {
-    impl_common! {(&'a K, &'a mut V), IterMut}
+    impl_common! {(&K, &V), (&K, &mut V), (&'a K, &'a mut V), IterMut}
 
-    /// Create a cursor over the passed list, setting the cursor position to the passed node.
+    /// Create a cursor over the first ordering of the passed list, setting the cursor position to the passed node.
     ///
     /// `position` must belong to the parent and be null, only if the parent is empty.
     #[inline]
     pub(crate) unsafe fn new(
-        parent: &'a mut LinkedMap<K, V, S>,
-        position: *mut Node<K, V>,
+        parent: &'a mut LinkedMap<K, V, S, N>,
+        position: *mut Node<K, V, N>,
+    ) -> Self {
+        Self::new_in(parent, 0, position)
+    }
+
+    /// Create a cursor over the ordering `order` of the passed list, setting the cursor position to the passed node.
+    ///
+    /// `position` must belong to the parent and be null, only if the parent is empty. `order` must be less than `N`.
+    #[inline]
+    pub(crate) unsafe fn new_in(
+        parent: &'a mut LinkedMap<K, V, S, N>,
+        order: usize,
+        position: *mut Node<K, V, N>,
     ) -> Self {
         Self {
             parent,
-            current: position,
+            current: Position::Owned(position),
+            order,
         }
     }
 
     /// Return a reference to the current node's value.
     /// Only returns None, if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 1)].into_iter().collect();
+    /// if let Some(v) = map.cursor_mut().value() {
+    ///     *v += 1;
+    /// }
+    /// assert_eq!(map.get(&1), Some(&2));
+    /// ```
     #[inline]
     pub fn value(&mut self) -> Option<&mut V> {
-        self.current().map(|n| &mut n.val)
+        NonNull::new(self.position()).map(|n| unsafe { &mut (*n.as_ptr()).val })
     }
 
-    /// Map pointer to key-value reference pair
+    /// Map pointer to key-value reference pair, borrowing the cursor
     #[inline]
-    fn map_ptr(node: *mut Node<K, V>) -> Option<(&'a K, &'a mut V)> {
-        unsafe { node.as_mut() }.map(|n| (&n.key, &mut n.val))
+    fn map_ptr(&self, node: *mut Node<K, V, N>) -> Option<(&K, &V)> {
+        NonNull::new(node).map(|n| {
+            let n = n.as_ptr();
+            unsafe { (&(*n).key, &(*n).val) }
+        })
     }
 
-    /// Map [NonNull] pointer to key-value reference pair
+    /// Map [NonNull] pointer to key and mutable value reference pair, borrowing the cursor
     #[inline]
-    fn map_non_null(mut node: NonNull<Node<K, V>>) -> (&'a K, &'a mut V) {
-        let n = unsafe { node.as_mut() };
-        (&n.key, &mut n.val)
+    fn map_non_null(&mut self, node: NonNull<Node<K, V, N>>) -> (&K, &mut V) {
+        let n = node.as_ptr();
+        unsafe { (&(*n).key, &mut (*n).val) }
     }
 
-    /// Insert a new node before the current one.
+    /// Map [NonNull] pointer to key and mutable value reference pair with the lifetime of the parent borrow.
     ///
-    /// If the list was empty, the cursor navigates to the inserted node.
+    /// Only references to the key and value are created, so references to the values of other nodes stay valid.
+    #[inline]
+    fn map_item(node: NonNull<Node<K, V, N>>) -> (&'a K, &'a mut V) {
+        let n = node.as_ptr();
+        unsafe { (&(*n).key, &mut (*n).val) }
+    }
+
+    /// Return a read-only cursor at the same position, that borrows this cursor.
     ///
-    /// If the key matches the current node, the value of the current node is updated instead.
-    pub fn insert_before(&mut self, key: K, val: V) {
-        match self.current() {
-            Some(current) => {
-                if current.key == key {
-                    current.val = val;
-                    return;
-                }
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let cursor = map.cursor_at_mut(&2).unwrap();
+    /// let mut view = cursor.as_cursor();
+    /// view.next();
+    /// assert_eq!(view.key(), Some(&3));
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn as_cursor(&self) -> Cursor<'_, K, V, S, N> {
+        unsafe { Cursor::new_in(&*self.parent, self.order, self.position()) }
+    }
 
-                match self.parent.map.entry(key.clone()) {
-                    Entry::Occupied(e) => unsafe {
-                        self.parent.list.insert_before(*e.get_mut(), current.into());
-                    },
-                    Entry::Vacant(e) => {
-                        let mut new = Node::new(key, val);
-                        unsafe {
-                            self.parent.list.insert_before(new, current.into());
-                        }
-                        e.insert(new);
-                    }
-                }
-            }
-            None => self.set_only_node(key, val), // List is empty
+    /// Return a mutable cursor, that borrows this cursor.
+    ///
+    /// Useful for passing the cursor to functions taking a [CursorMut] by value, without giving it up. The returned
+    /// cursor shares its position with this one, so navigating or removing through it also moves this cursor, like
+    /// it would through a `&mut CursorMut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{CursorMut, LinkedMap};
+    /// use std::hash::BuildHasher;
+    ///
+    /// fn skip_to_even<S: BuildHasher>(mut c: CursorMut<'_, i32, (), S>) {
+    ///     let _ = c.seek_forward(|k, _| k % 2 == 0);
+    /// }
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// skip_to_even(cursor.reborrow());
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn reborrow(&mut self) -> CursorMut<'_, K, V, S, N> {
+        let slot = match &mut self.current {
+            Position::Owned(node) => node,
+            Position::Borrowed(slot) => &mut **slot,
+        };
+        CursorMut {
+            parent: &mut *self.parent,
+            current: Position::Borrowed(slot),
+            order: self.order,
         }
     }
 
-    /// Insert a new node after the current one.
+    /// Convert into a read-only cursor at the same position, borrowing the map for the rest of the lifetime
     ///
-    /// If the list was empty, the cursor navigates to the inserted node.
+    /// # Examples
     ///
-    /// If the key matches the current node, the value of the current node is updated instead.
-    pub fn insert_after(&mut self, key: K, val: V) {
-        match unsafe { self.current.as_mut() } {
-            Some(current) => {
-                if current.key == key {
-                    current.val = val;
-                    return;
-                }
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.move_to_back();
+    /// let a = cursor.into_cursor();
+    /// let b = a.clone();
+    /// assert_eq!(a.key(), b.key());
+    /// ```
+    #[inline]
+    pub fn into_cursor(self) -> Cursor<'a, K, V, S, N> {
+        let position = self.position();
+        unsafe { Cursor::new_in(self.parent, self.order, position) }
+    }
+
+    /// Helper for reading the position of the cursor
+    #[inline]
+    fn position(&self) -> *mut Node<K, V, N> {
+        match &self.current {
+            Position::Owned(node) => *node,
+            Position::Borrowed(slot) => **slot,
+        }
+    }
+
+    /// Helper for setting the position of the cursor
+    #[inline]
+    fn set_position(&mut self, node: *mut Node<K, V, N>) {
+        match &mut self.current {
+            Position::Owned(current) => *current = node,
+            Position::Borrowed(slot) => **slot = node,
+        }
+    }
+
+    /// Insert a key-value pair before the current node.
+    ///
+    /// If the list was empty, the cursor navigates to the inserted node. Otherwise the cursor stays at the current
+    /// node.
+    ///
+    /// If the key is already in the map, its value is replaced and its node is moved before the current one. If the
+    /// key matches the current node, only the value of the current node is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{InsertOutcome, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'a'), (2, 'b'), (3, 'c')].into_iter().collect();
+    /// let mut cursor = map.cursor_at_mut(&1).unwrap();
+    /// assert_eq!(cursor.insert_before(0, 'z'), InsertOutcome::Inserted);
+    /// assert_eq!(cursor.insert_before(3, 'x'), InsertOutcome::Moved { old_value: 'c' });
+    /// assert_eq!(cursor.insert_before(1, 'y'), InsertOutcome::UpdatedInPlace { old_value: 'a' });
+    /// assert_eq!(cursor.key_value(), Some((&1, &'y')));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [0, 3, 1, 2]);
+    /// ```
+    #[inline]
+    pub fn insert_before(&mut self, key: K, val: V) -> InsertOutcome<V> {
+        self.insert_next_to(key, val, false, true)
+    }
 
-                match self.parent.map.entry(key.clone()) {
-                    Entry::Occupied(e) => unsafe {
-                        self.parent.list.insert_after(*e.get_mut(), current.into());
-                    },
-                    Entry::Vacant(e) => {
-                        let mut new = Node::new(key, val);
-                        unsafe {
-                            self.parent.list.insert_after(new, current.into());
-                        }
-                        e.insert(new);
+    /// Insert a key-value pair after the current node.
+    ///
+    /// If the list was empty, the cursor navigates to the inserted node. Otherwise the cursor stays at the current
+    /// node.
+    ///
+    /// If the key is already in the map, its value is replaced and its node is moved after the current one. If the
+    /// key matches the current node, only the value of the current node is replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{InsertOutcome, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'a'), (2, 'b')].into_iter().collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert_eq!(cursor.insert_after(3, 'c'), InsertOutcome::Inserted);
+    /// assert_eq!(cursor.insert_after(2, 'x'), InsertOutcome::Moved { old_value: 'b' });
+    /// assert_eq!(cursor.key(), Some(&1));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn insert_after(&mut self, key: K, val: V) -> InsertOutcome<V> {
+        self.insert_next_to(key, val, true, true)
+    }
+
+    /// Insert a key-value pair before the current node, if the key is not in the map yet.
+    ///
+    /// If the key is already in the map, its value is replaced, without moving its node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{InsertOutcome, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'a'), (2, 'b')].into_iter().collect();
+    /// let mut cursor = map.cursor_at_mut(&1).unwrap();
+    /// assert_eq!(cursor.insert_before_or_update(2, 'x'), InsertOutcome::UpdatedInPlace { old_value: 'b' });
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &'a'), (&2, &'x')]);
+    /// ```
+    #[inline]
+    pub fn insert_before_or_update(&mut self, key: K, val: V) -> InsertOutcome<V> {
+        self.insert_next_to(key, val, false, false)
+    }
+
+    /// Insert a key-value pair after the current node, if the key is not in the map yet.
+    ///
+    /// If the key is already in the map, its value is replaced, without moving its node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{InsertOutcome, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'a'), (2, 'b')].into_iter().collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// assert_eq!(cursor.insert_after_or_update(1, 'x'), InsertOutcome::UpdatedInPlace { old_value: 'a' });
+    /// assert_eq!(cursor.insert_after_or_update(3, 'c'), InsertOutcome::Inserted);
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [(&1, &'x'), (&2, &'b'), (&3, &'c')]);
+    /// ```
+    #[inline]
+    pub fn insert_after_or_update(&mut self, key: K, val: V) -> InsertOutcome<V> {
+        self.insert_next_to(key, val, true, false)
+    }
+
+    /// Insert a key-value pair next to the current node. Existing nodes of the key are moved there, only if
+    /// `move_existing` is set.
+    fn insert_next_to(
+        &mut self,
+        key: K,
+        val: V,
+        after: bool,
+        move_existing: bool,
+    ) -> InsertOutcome<V> {
+        let current = match NonNull::new(self.position()) {
+            Some(current) => current,
+            None => {
+                // List is empty
+                self.set_only_node(key, val);
+                return InsertOutcome::Inserted;
+            }
+        };
+
+        match self.parent.map.entry(key) {
+            Entry::Occupied(e) => {
+                let mut node = *e.get();
+                let old_value = std::mem::replace(unsafe { &mut node.as_mut().val }, val);
+                if node == current || !move_existing {
+                    return InsertOutcome::UpdatedInPlace { old_value };
+                }
+                unsafe {
+                    if after {
+                        self.parent.list.move_after(self.order, node, current);
+                    } else {
+                        self.parent.list.move_before(self.order, node, current);
                     }
-                };
+                }
+                self.parent.debug_check_invariants();
+                InsertOutcome::Moved { old_value }
+            }
+            Entry::Vacant(e) => {
+                let new = Node::new(e.key().clone(), val);
+                unsafe {
+                    if after {
+                        self.parent.list.insert_after(self.order, new, current);
+                    } else {
+                        self.parent.list.insert_before(self.order, new, current);
+                    }
+                }
+                e.insert(new);
+                self.parent.debug_check_invariants();
+                InsertOutcome::Inserted
             }
-            None => self.set_only_node(key, val), // List is empty
         }
     }
 
+    /// Replace the key of the current node with `new`, keeping its value and position, and return the old key.
+    ///
+    /// Fails without modifying the map, if the map is empty, or if `new` is already in the map under a different
+    /// node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, RekeyError};
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// assert_eq!(cursor.replace_key(20), Ok(2));
+    /// assert_eq!(cursor.replace_key(3), Err(RekeyError::Occupied(3)));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 20, 3]);
+    /// assert!(map.contains_key(&20) && !map.contains_key(&2));
+    /// ```
+    pub fn replace_key(&mut self, new: K) -> Result<K, RekeyError<K>> {
+        let mut current = match NonNull::new(self.position()) {
+            Some(current) => current,
+            None => return Err(RekeyError::NotFound(new)),
+        };
+        let node = unsafe { current.as_mut() };
+        if node.key != new && self.parent.map.contains_key(&new) {
+            return Err(RekeyError::Occupied(new));
+        }
+
+        self.parent.map.remove(&node.key);
+        let old = std::mem::replace(&mut node.key, new.clone());
+        self.parent.map.insert(new, current);
+        self.parent.debug_check_invariants();
+        Ok(old)
+    }
+
     /// Set the only node in the list. Only call this, when list is empty.
     #[cold]
     fn set_only_node(&mut self, key: K, val: V) {
         let new = self.parent.list.append(key.clone(), val);
         self.parent.map.insert(key, new);
-        self.current = new.as_ptr();
+        self.set_position(new.as_ptr());
+        self.parent.debug_check_invariants();
     }
 
     /// Remove the current node and return its key and value.
@@ -309,25 +1001,180 @@ where
     /// Navigates the cursor to the previous node.
     /// If removed node was the head of the list, navigates it to the next node.
     /// If the list becomes empty, the cursor points to no node after the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// assert_eq!(cursor.remove(), Some((2, ())));
+    /// assert_eq!(cursor.key(), Some(&1));
+    /// assert_eq!(cursor.remove(), Some((1, ())));
+    /// assert_eq!(cursor.key(), Some(&3));
+    /// ```
+    #[inline]
     pub fn remove(&mut self) -> Option<(K, V)> {
-        unsafe { self.current.as_mut() }.map(|current| {
-            let navigate_to = if !current.previous().is_null() {
-                current.previous()
-            } else {
-                current.next()
-            };
+        self.remove_and_retreat()
+    }
 
-            self.parent.list.remove(current.into());
-            if self.parent.saved == current {
-                self.parent.saved = null_mut();
-            }
+    /// Remove the current node and return its key and value.
+    /// Returns [None], if list is empty.
+    ///
+    /// Navigates the cursor to the previous node.
+    /// If removed node was the head of the list, navigates it to the next node.
+    /// If the list becomes empty, the cursor points to no node after the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.to_back();
+    /// while let Some((k, _)) = cursor.remove_and_retreat() {
+    ///     if k == 2 {
+    ///         break;
+    ///     }
+    /// }
+    /// assert_eq!(cursor.key(), Some(&1));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1]);
+    /// ```
+    pub fn remove_and_retreat(&mut self) -> Option<(K, V)> {
+        NonNull::new(self.position()).map(|current| {
+            let navigate_to = match unsafe { current.as_ref() }.previous(self.order) {
+                previous if !previous.is_null() => previous,
+                _ => unsafe { current.as_ref() }.next(self.order),
+            };
+            self.set_position(navigate_to);
+            self.remove_node(current)
+        })
+    }
 
-            let current = unsafe { Box::from_raw(current) };
-            self.current = navigate_to;
-            (current.key, current.val)
+    /// Remove the current node and return its key and value.
+    /// Returns [None], if list is empty.
+    ///
+    /// Navigates the cursor to the next node.
+    /// If removed node was the tail of the list, navigates it to the previous node.
+    /// If the list becomes empty, the cursor points to no node after the call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=6).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// while cursor.seek_forward(|k, _| k % 2 == 0).is_ok() {
+    ///     cursor.remove_and_advance();
+    /// }
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 3, 5]);
+    /// ```
+    pub fn remove_and_advance(&mut self) -> Option<(K, V)> {
+        NonNull::new(self.position()).map(|current| {
+            let navigate_to = match unsafe { current.as_ref() }.next(self.order) {
+                next if !next.is_null() => next,
+                _ => unsafe { current.as_ref() }.previous(self.order),
+            };
+            self.set_position(navigate_to);
+            self.remove_node(current)
         })
     }
 
+    /// Remove the node after the current one and return its key and value, without moving the cursor.
+    /// Returns [None], if the cursor is at the tail or the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// assert_eq!(cursor.remove_next(), Some((3, ())));
+    /// assert_eq!(cursor.remove_next(), None);
+    /// assert_eq!(cursor.remove_previous(), Some((1, ())));
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// ```
+    pub fn remove_next(&mut self) -> Option<(K, V)> {
+        let next = NonNull::new(self.current()?.next(self.order))?;
+        Some(self.remove_node(next))
+    }
+
+    /// Remove the node before the current one and return its key and value, without moving the cursor.
+    /// Returns [None], if the cursor is at the head or the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// assert_eq!(cursor.remove_previous(), Some((1, ())));
+    /// assert_eq!(cursor.remove_previous(), None);
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// ```
+    pub fn remove_previous(&mut self) -> Option<(K, V)> {
+        let previous = NonNull::new(self.current()?.previous(self.order))?;
+        Some(self.remove_node(previous))
+    }
+
+    /// Unlink a node, that the cursor is not at, drop it from the map and return its key and value
+    fn remove_node(&mut self, node: NonNull<Node<K, V, N>>) -> (K, V) {
+        self.parent.list.remove(node);
+        self.parent.map.remove(unsafe { &node.as_ref().key });
+        if self.parent.saved == node.as_ptr() {
+            self.parent.saved = null_mut();
+        }
+
+        self.parent.debug_check_invariants();
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        (node.key, node.val)
+    }
+
+    /// Replace the value of the current node and return the old one.
+    /// Returns [None] and drops `val`, if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'a')].into_iter().collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert_eq!(cursor.replace_value('b'), Some('a'));
+    /// if let Some((_, v)) = cursor.key_value_mut() {
+    ///     *v = 'c';
+    /// }
+    /// assert_eq!(map.get(&1), Some(&'c'));
+    /// ```
+    #[inline]
+    pub fn replace_value(&mut self, val: V) -> Option<V> {
+        self.value().map(|current| std::mem::replace(current, val))
+    }
+
+    /// Return the current node's key and a mutable reference to its value.
+    /// Only returns None, if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [("ab", 1)].into_iter().collect();
+    /// if let Some((k, v)) = map.cursor_mut().key_value_mut() {
+    ///     *v += k.len();
+    /// }
+    /// assert_eq!(map.get(&"ab"), Some(&3));
+    /// ```
+    #[inline]
+    pub fn key_value_mut(&mut self) -> Option<(&K, &mut V)> {
+        NonNull::new(self.position()).map(|n| self.map_non_null(n))
+    }
+
     /// Remember the current cursor position for efficiently navigating to the this node later on using the
     /// `resume()` methods on [Cursor], [CursorMut] and [LinkedMap] or the `get_saved()` and `resume_mut()` methods on
     /// [LinkedMap].
@@ -336,28 +1183,402 @@ where
     /// new modes anywhere in the list or changing the saved node's siblings does not.
     ///
     /// Only up to 1 node can be saved on a  [LinkedMap] at any given time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// map.cursor_at_mut(&2).unwrap().save();
+    /// map.prepend(0, ());
+    /// assert_eq!(map.resume().unwrap().key(), Some(&2));
+    /// ```
     #[inline]
     pub fn save(&mut self) {
-        self.parent.saved = self.current;
+        self.parent.saved = self.position();
+        self.parent.debug_check_invariants();
     }
 
     /// Clear any saved node. See [CursorMut::save()](CursorMut::save) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&2).unwrap();
+    /// cursor.save();
+    /// cursor.clear_saved();
+    /// assert!(map.resume().is_none());
+    /// ```
     #[inline]
     pub fn clear_saved(&mut self) {
         self.parent.clear_saved()
     }
 
-    /// Move the current node to the front of the list
+    /// Move the current node to the front of the cursor's ordering
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&3).unwrap();
+    /// cursor.move_to_front();
+    /// assert_eq!(cursor.key(), Some(&3));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 1, 2]);
+    /// ```
     pub fn move_to_front(&mut self) {
-        if let Some(current) = self.current() {
-            self.parent.list.move_to_front(current.into());
+        if let Some(current) = NonNull::new(self.position()) {
+            self.parent.list.move_to_front(self.order, current);
         }
+        self.parent.debug_check_invariants();
     }
 
-    /// Move the current node to the back of the list
+    /// Move the current node to the back of the cursor's ordering
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// cursor.move_to_back();
+    /// assert_eq!(cursor.key(), Some(&1));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// ```
     pub fn move_to_back(&mut self) {
-        if let Some(current) = self.current() {
-            self.parent.list.move_to_back(current.into());
+        if let Some(current) = NonNull::new(self.position()) {
+            self.parent.list.move_to_back(self.order, current);
+        }
+        self.parent.debug_check_invariants();
+    }
+
+    /// Move the current node right before the node of the `anchor` key.
+    /// The cursor stays on the moved node.
+    ///
+    /// Returns `false`, if the list is empty or `anchor` is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut(&3).unwrap();
+    /// assert!(cursor.move_before_key(&1));
+    /// assert!(!cursor.move_before_key(&4));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 1, 2]);
+    /// ```
+    pub fn move_before_key(&mut self, anchor: &K) -> bool {
+        match (NonNull::new(self.position()), self.parent.map.get(anchor)) {
+            (Some(current), Some(&anchor)) => {
+                unsafe { self.parent.list.move_before(self.order, current, anchor) };
+                self.parent.debug_check_invariants();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the current node right after the node of the `anchor` key.
+    /// The cursor stays on the moved node.
+    ///
+    /// Returns `false`, if the list is empty or `anchor` is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert!(cursor.move_after_key(&3));
+    /// assert!(!cursor.move_after_key(&4));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// ```
+    pub fn move_after_key(&mut self, anchor: &K) -> bool {
+        match (NonNull::new(self.position()), self.parent.map.get(anchor)) {
+            (Some(current), Some(&anchor)) => {
+                unsafe { self.parent.list.move_after(self.order, current, anchor) };
+                self.parent.debug_check_invariants();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Swap the current node with the next one.
+    /// The cursor stays on the current node, which is now one position closer to the tail.
+    ///
+    /// Returns `false`, if there is no next node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert!(cursor.swap_with_next());
+    /// assert_eq!(cursor.key(), Some(&1));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 1, 3]);
+    /// ```
+    pub fn swap_with_next(&mut self) -> bool {
+        match self
+            .current()
+            .and_then(|n| NonNull::new(n.next(self.order)))
+        {
+            Some(next) => {
+                unsafe {
+                    self.parent
+                        .list
+                        .swap(self.order, NonNull::new_unchecked(self.position()), next)
+                };
+                self.parent.debug_check_invariants();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Swap the current node with the previous one.
+    /// The cursor stays on the current node, which is now one position closer to the head.
+    ///
+    /// Returns `false`, if there is no previous node.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut();
+    /// assert!(!cursor.swap_with_previous());
+    /// cursor.to_back();
+    /// assert!(cursor.swap_with_previous());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 3, 2]);
+    /// ```
+    pub fn swap_with_previous(&mut self) -> bool {
+        match self
+            .current()
+            .and_then(|n| NonNull::new(n.previous(self.order)))
+        {
+            Some(previous) => {
+                unsafe {
+                    self.parent.list.swap(
+                        self.order,
+                        NonNull::new_unchecked(self.position()),
+                        previous,
+                    )
+                };
+                self.parent.debug_check_invariants();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Rotate the cursor's ordering, so that the current node becomes its head, by relinking the ends of the
+    /// ordering.
+    ///
+    /// Only the shorter of the runs before the current node and from it on is relabeled, so this takes
+    /// O(min(k, n - k)) for the current node at index `k`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=4).map(|i| (i, ())).collect();
+    /// map.cursor_at_mut(&3).unwrap().make_head();
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 4, 1, 2]);
+    /// ```
+    pub fn make_head(&mut self) {
+        if let Some(current) = NonNull::new(self.position()) {
+            unsafe { self.parent.list.make_head(self.order, current) };
+        }
+        self.parent.debug_check_invariants();
+    }
+}
+
+impl<'a, K, V, S> CursorMut<'a, K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Insert a key-value pair at its sorted position according to `cmp`, assuming the entries are already sorted
+    /// by `cmp`, and navigate the cursor to it.
+    ///
+    /// The position is found by scanning from the current node. See
+    /// [LinkedMap::insert_sorted_by](LinkedMap::insert_sorted_by) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, ()), (3, ()), (5, ())].into_iter().collect();
+    /// let mut cursor = map.cursor_at_mut(&3).unwrap();
+    /// assert_eq!(cursor.insert_sorted_by(4, (), |a, _, b, _| a.cmp(b)), None);
+    /// assert_eq!(cursor.key(), Some(&4));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 3, 4, 5]);
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, key: K, val: V, cmp: F) -> Option<V>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let (node, old) = unsafe {
+            self.parent
+                .insert_sorted_from(key, val, self.position(), cmp)
+        };
+        self.set_position(node.as_ptr());
+        old
+    }
+
+    /// Move all entries of `other` after the current node, leaving `other` empty, unless entries are skipped.
+    ///
+    /// If the list was empty, the cursor navigates to the first inserted node.
+    ///
+    /// Keys present in both maps are handled according to `policy`.
+    /// With [SplicePolicy::Error] neither map is modified, if such a key is found.
+    ///
+    /// If the saved node of `other` is moved and this map has no saved node, it is saved on this map instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, SplicePolicy};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, "a"), (4, "d")].into_iter().collect();
+    /// let mut other: LinkedMap<_, _> = [(2, "b"), (3, "c")].into_iter().collect();
+    ///
+    /// map.cursor_mut().splice_after(&mut other, SplicePolicy::Error).unwrap();
+    /// assert!(other.is_empty());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    pub fn splice_after(
+        &mut self,
+        other: &mut LinkedMap<K, V, S>,
+        policy: SplicePolicy,
+    ) -> Result<(), SpliceError<K>> {
+        unsafe { self.parent.splice(self.position(), other, policy) }?;
+        if self.position().is_null() {
+            self.set_position(self.parent.list.head(0));
+        }
+        Ok(())
+    }
+
+    /// Move all entries of `other` before the current node, leaving `other` empty, unless entries are skipped.
+    ///
+    /// If the list was empty, the cursor navigates to the first inserted node.
+    ///
+    /// Keys present in both maps are handled according to `policy`.
+    /// With [SplicePolicy::Error] neither map is modified, if such a key is found.
+    ///
+    /// If the saved node of `other` is moved and this map has no saved node, it is saved on this map instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, SplicePolicy};
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, "a"), (4, "d")].into_iter().collect();
+    /// let mut other: LinkedMap<_, _> = [(2, "b"), (4, "D"), (3, "c")].into_iter().collect();
+    ///
+    /// let mut c = map.cursor_at_mut(&4).unwrap();
+    /// let err = c.splice_before(&mut other, SplicePolicy::Error).unwrap_err();
+    /// assert_eq!(err.key, 4);
+    /// assert_eq!(err.to_string(), "key 4 present in both maps");
+    ///
+    /// c.splice_before(&mut other, SplicePolicy::Skip).unwrap();
+    /// assert_eq!(other.iter().collect::<Vec<_>>(), [(&4, &"D")]);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    ///
+    /// let mut other: LinkedMap<_, _> = [(4, "D"), (5, "e")].into_iter().collect();
+    /// map.cursor_mut().splice_before(&mut other, SplicePolicy::Overwrite).unwrap();
+    /// assert_eq!(
+    ///     map.iter().collect::<Vec<_>>(),
+    ///     [(&4, &"D"), (&5, &"e"), (&1, &"a"), (&2, &"b"), (&3, &"c")],
+    /// );
+    /// ```
+    pub fn splice_before(
+        &mut self,
+        other: &mut LinkedMap<K, V, S>,
+        policy: SplicePolicy,
+    ) -> Result<(), SpliceError<K>> {
+        let after = match self.current() {
+            Some(current) => current.previous(0),
+            None => null_mut(),
+        };
+        unsafe { self.parent.splice(after, other, policy) }?;
+        if self.position().is_null() {
+            self.set_position(self.parent.list.head(0));
+        }
+        Ok(())
+    }
+}
+
+impl<'a, K, V, S> CursorMut<'a, K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher + Clone,
+{
+    /// Split the map in two after the current node.
+    ///
+    /// Returns a new map containing all nodes after the current one, with their keys moved to the new map.
+    /// If the saved node is among the detached nodes, it is saved on the new map instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, i * 10)).collect();
+    /// let tail = map.cursor_at_mut(&3).unwrap().split_after();
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(tail.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [4, 5]);
+    /// assert_eq!(tail.len(), 2);
+    /// ```
+    pub fn split_after(&mut self) -> LinkedMap<K, V, S> {
+        match NonNull::new(self.position()) {
+            Some(current) => {
+                let detached = unsafe { self.parent.list.split_after(current) };
+                self.parent.adopt_detached(detached)
+            }
+            None => LinkedMap::with_hasher(self.parent.map.hasher().clone()),
+        }
+    }
+
+    /// Split the map in two before the current node.
+    ///
+    /// Returns a new map containing all nodes before the current one, with their keys moved to the new map.
+    /// If the saved node is among the detached nodes, it is saved on the new map instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, i * 10)).collect();
+    /// let head = map.cursor_at_mut(&3).unwrap().split_before();
+    /// assert_eq!(head.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 4, 5]);
+    /// assert_eq!(map.len(), 3);
+    /// ```
+    pub fn split_before(&mut self) -> LinkedMap<K, V, S> {
+        match NonNull::new(self.position()) {
+            Some(current) => {
+                let detached = unsafe { self.parent.list.split_before(current) };
+                self.parent.adopt_detached(detached)
+            }
+            None => LinkedMap::with_hasher(self.parent.map.hasher().clone()),
         }
     }
 }
@@ -0,0 +1,56 @@
+//! Structural validation of the list links and order labels
+
+use super::{LinkedList, Node};
+use crate::InvariantViolation;
+use hashbrown::HashMap;
+use std::ptr::null_mut;
+
+impl<K, V, const N: usize> LinkedList<K, V, N> {
+    /// Walk every ordering from head to tail, checking that the links are symmetric, the ordering ends at its tail,
+    /// the labels increase and every ordering holds the same nodes as the first one.
+    ///
+    /// Returns the position of every node in the first ordering.
+    pub fn check_links(&self) -> Result<HashMap<*mut Node<K, V, N>, usize>, InvariantViolation> {
+        let positions = self.check_ordering(0)?;
+        for order in 1..N {
+            let other = self.check_ordering(order)?;
+            if other.len() != positions.len()
+                || other.keys().any(|node| !positions.contains_key(node))
+            {
+                return Err(InvariantViolation::OrderingMismatch { order });
+            }
+        }
+        Ok(positions)
+    }
+
+    /// Check the links and labels of `order` and return the position of every node in it
+    fn check_ordering(
+        &self,
+        order: usize,
+    ) -> Result<HashMap<*mut Node<K, V, N>, usize>, InvariantViolation> {
+        let mut positions = HashMap::new();
+        let mut previous: *mut Node<K, V, N> = null_mut();
+        let mut next = self.head(order);
+        while let Some(node) = unsafe { next.as_ref() } {
+            let index = positions.len();
+            if positions.insert(next, index).is_some() {
+                return Err(InvariantViolation::Cycle { index });
+            }
+            if node.previous(order) != previous {
+                return Err(InvariantViolation::AsymmetricLink { index });
+            }
+            if let Some(p) = unsafe { previous.as_ref() } {
+                if p.links[order].label >= node.links[order].label {
+                    return Err(InvariantViolation::LabelOrder { index });
+                }
+            }
+            previous = next;
+            next = node.next(order);
+        }
+
+        if self.tail(order) != previous {
+            return Err(InvariantViolation::TailMismatch);
+        }
+        Ok(positions)
+    }
+}
@@ -1,159 +1,530 @@
 pub mod node;
 pub use node::Node;
 
+mod check;
+mod order;
+mod sort;
+
 use std::ptr::{null_mut, NonNull};
 
-/// Doubly-linked list with cursor iteration support
-pub struct LinkedList<K, V> {
-    /// First node of list. `null`, if list is empty.
-    head: *mut Node<K, V>,
+/// First and last node of one ordering of a [LinkedList]. Both are `null`, if the list is empty.
+struct Ends<K, V, const N: usize> {
+    head: *mut Node<K, V, N>,
+    tail: *mut Node<K, V, N>,
+}
 
-    /// Last node of the list. `null`, if list is empty.
-    tail: *mut Node<K, V>,
+impl<K, V, const N: usize> Clone for Ends<K, V, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
 }
 
-impl<K, V> Drop for LinkedList<K, V> {
+impl<K, V, const N: usize> Copy for Ends<K, V, N> {}
+
+/// Doubly-linked list with cursor iteration support.
+///
+/// Every node is linked into each of the `N` orderings of the list. Operations rearranging nodes take the ordering
+/// they apply to and leave all other orderings untouched, while nodes are linked into and removed from all orderings
+/// at once. Runs of nodes can only be moved between lists with a single ordering.
+pub struct LinkedList<K, V, const N: usize = 1> {
+    /// First and last node of every ordering
+    ends: [Ends<K, V, N>; N],
+}
+
+impl<K, V, const N: usize> Drop for LinkedList<K, V, N> {
     fn drop(&mut self) {
-        let mut next = self.head;
+        let mut next = self.head(0);
         while !next.is_null() {
             let b = unsafe { Box::from_raw(next) };
-            next = b.next();
+            next = b.next(0);
         }
     }
 }
 
-impl<K, V> Default for LinkedList<K, V> {
+impl<K, V, const N: usize> Default for LinkedList<K, V, N> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl<K, V> LinkedList<K, V> {
+impl<K, V, const N: usize> LinkedList<K, V, N> {
     /// Create new empty list
     #[inline]
     pub const fn new() -> Self {
         Self {
-            head: null_mut(),
-            tail: null_mut(),
+            ends: [Ends {
+                head: null_mut(),
+                tail: null_mut(),
+            }; N],
         }
     }
 
-    /// Return pointer to list head
-    pub fn head(&self) -> *mut Node<K, V> {
-        self.head
+    /// Return pointer to the head of `order`
+    pub fn head(&self, order: usize) -> *mut Node<K, V, N> {
+        self.ends[order].head
     }
 
-    /// Return pointer to list tail
-    pub fn tail(&self) -> *mut Node<K, V> {
-        self.tail
+    /// Return pointer to the tail of `order`
+    pub fn tail(&self, order: usize) -> *mut Node<K, V, N> {
+        self.ends[order].tail
     }
 
-    /// Append a node to the end of the list and return a pointer to it
+    /// Append a node to the end of every ordering and return a pointer to it
     #[inline]
-    pub fn append(&mut self, k: K, v: V) -> NonNull<Node<K, V>> {
+    pub fn append(&mut self, k: K, v: V) -> NonNull<Node<K, V, N>> {
         let node = Node::new(k, v);
-        match unsafe { self.tail.as_mut() } {
-            Some(tail) => unsafe {
-                self.insert_after(node, tail.into());
-            },
-            None => {
-                self.head = node.as_ptr();
-                self.tail == node.as_ptr();
-            }
-        }
+        self.append_node(node);
         node
     }
 
-    /// Prepend a node to the start the list and return a pointer to it
+    /// Prepend a node to the start of every ordering and return a pointer to it
     #[inline]
-    pub fn prepend(&mut self, k: K, v: V) -> NonNull<Node<K, V>> {
+    pub fn prepend(&mut self, k: K, v: V) -> NonNull<Node<K, V, N>> {
         let node = Node::new(k, v);
-        match unsafe { self.head.as_mut() } {
-            Some(head) => unsafe {
-                self.insert_before(node, head.into());
-            },
-            None => {
-                self.head = node.as_ptr();
-                self.tail == node.as_ptr();
-            }
-        }
+        self.prepend_node(node);
         node
     }
 
-    /// Move the node to the front of the list
-    pub fn move_to_front(&mut self, node: NonNull<Node<K, V>>) {
-        let node = unsafe { node.as_mut() };
-        if self.head == node {
+    /// Append an existing node, that is not linked into any list, to the end of every ordering
+    #[inline]
+    pub fn append_node(&mut self, node: NonNull<Node<K, V, N>>) {
+        for order in 0..N {
+            self.link_back(order, node);
+        }
+    }
+
+    /// Prepend an existing node, that is not linked into any list, to the start of every ordering
+    #[inline]
+    pub fn prepend_node(&mut self, node: NonNull<Node<K, V, N>>) {
+        for order in 0..N {
+            self.link_front(order, node);
+        }
+    }
+
+    /// Move the node to the front of `order`
+    pub fn move_to_front(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        if self.head(order) != node.as_ptr() {
+            self.unlink(order, node);
+            self.link_front(order, node);
+        }
+    }
+
+    /// Move the node to the back of `order`
+    pub fn move_to_back(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        if self.tail(order) != node.as_ptr() {
+            self.unlink(order, node);
+            self.link_back(order, node);
+        }
+    }
+
+    /// Move the node right before the `anchor` node in `order`
+    ///
+    /// # SAFETY
+    ///
+    /// `node` and `anchor` must belong to this list.
+    pub unsafe fn move_before(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        anchor: NonNull<Node<K, V, N>>,
+    ) {
+        if node == anchor || anchor.as_ref().previous(order) == node.as_ptr() {
+            return;
+        }
+        self.unlink(order, node);
+        self.link_before(order, node, anchor);
+    }
+
+    /// Move the node right after the `anchor` node in `order`
+    ///
+    /// # SAFETY
+    ///
+    /// `node` and `anchor` must belong to this list.
+    pub unsafe fn move_after(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        anchor: NonNull<Node<K, V, N>>,
+    ) {
+        if node == anchor || anchor.as_ref().next(order) == node.as_ptr() {
             return;
         }
+        self.unlink(order, node);
+        self.link_after(order, node, anchor);
+    }
 
-        if self.tail == node {
-            self.tail = node.previous();
+    /// Swap the positions of two nodes in `order`
+    ///
+    /// # SAFETY
+    ///
+    /// `a` and `b` must belong to this list.
+    pub unsafe fn swap(
+        &mut self,
+        order: usize,
+        a: NonNull<Node<K, V, N>>,
+        b: NonNull<Node<K, V, N>>,
+    ) {
+        if a == b {
+            return;
+        }
+        if a.as_ref().next(order) == b.as_ptr() {
+            return self.move_after(order, a, b);
+        }
+        if b.as_ref().next(order) == a.as_ptr() {
+            return self.move_after(order, b, a);
+        }
+
+        // Not adjacent, so `b` can be reinserted at the old position of `a` relative to its old next node
+        let a_next = a.as_ref().next(order);
+        self.move_after(order, a, b);
+        self.unlink(order, b);
+        match NonNull::new(a_next) {
+            Some(a_next) => self.link_before(order, b, a_next),
+            None => self.link_back(order, b),
         }
-        node.remove();
+    }
 
-        unsafe { self.head.as_mut() }
-            .expect("list head to be set")
-            .insert_before(node.into());
-        self.head = node;
+    /// Reverse `order` by flipping the links and mirroring the order label of every node
+    pub fn reverse(&mut self, order: usize) {
+        let mut next = self.head(order);
+        while !next.is_null() {
+            let node = next;
+            unsafe {
+                let links = &mut (*node).links[order];
+                next = links.next;
+                links.next = links.previous;
+                links.previous = next;
+                links.label = order::LABEL_LIMIT - 1 - links.label;
+            }
+        }
+        let ends = &mut self.ends[order];
+        std::mem::swap(&mut ends.head, &mut ends.tail);
     }
 
-    /// Move the node to the back of the list
-    pub fn move_to_back(&mut self, node: NonNull<Node<K, V>>) {
-        let node = unsafe { node.as_mut() };
-        if self.tail == node {
+    /// Rotate `order`, so that `node` becomes the head, by relinking the ends of the ordering.
+    ///
+    /// Only the shorter of the runs before `node` and from `node` on is relabeled, so this takes O(min(k, n - k))
+    /// for `node` at index `k`.
+    ///
+    /// # SAFETY
+    ///
+    /// `node` must belong to this list.
+    pub unsafe fn make_head(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        let Ends { head, tail } = self.ends[order];
+        if head == node.as_ptr() {
             return;
         }
 
-        if self.head == node {
-            self.head = node.next();
+        // Walk both runs in lockstep until the shorter one ends
+        let mut before = node.as_ref().previous(order);
+        let mut from = node.as_ref().next(order);
+        let mut steps = 0;
+        while !before.is_null() && !from.is_null() {
+            before = (*before).previous(order);
+            from = (*from).next(order);
+            steps += 1;
         }
-        node.remove();
 
-        unsafe { self.tail.as_mut() }
-            .expect("list tail to be set")
-            .insert_before(node.into());
-        self.tail = node;
+        // Close the ordering into a ring and open it again right before `node`
+        let last = node.as_ref().previous(order);
+        (*tail).links[order].next = head;
+        (*head).links[order].previous = tail;
+        (*last).links[order].next = null_mut();
+        (*node.as_ptr()).links[order].previous = null_mut();
+        self.ends[order] = Ends {
+            head: node.as_ptr(),
+            tail: last,
+        };
+
+        if before.is_null() {
+            self.label_run(
+                order,
+                NonNull::new_unchecked(head),
+                NonNull::new_unchecked(last),
+                steps,
+            );
+        } else {
+            self.label_run(order, node, NonNull::new_unchecked(tail), steps + 1);
+        }
+    }
+
+    /// Remove a node from every ordering of the list
+    pub fn remove(&mut self, node: NonNull<Node<K, V, N>>) {
+        for order in 0..N {
+            self.unlink(order, node);
+        }
+    }
+
+    /// Insert a node, that is not linked into any list, before a different node in `order` and at the end of all
+    /// other orderings.
+    ///
+    /// # SAFETY
+    ///
+    /// `node` and `before` must not be the same node.
+    #[inline]
+    pub unsafe fn insert_before(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        before: NonNull<Node<K, V, N>>,
+    ) {
+        self.link_before(order, node, before);
+        self.link_others_back(order, node);
+    }
+
+    /// Insert a node, that is not linked into any list, after a different node in `order` and at the end of all
+    /// other orderings.
+    ///
+    /// # SAFETY
+    ///
+    /// `node` and `after` must not be the same node.
+    #[inline]
+    pub unsafe fn insert_after(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        after: NonNull<Node<K, V, N>>,
+    ) {
+        self.link_after(order, node, after);
+        self.link_others_back(order, node);
     }
 
-    /// Remove a node from the list
-    pub fn remove(&mut self, node: NonNull<Node<K, V>>) {
-        let node = unsafe { node.as_mut() };
-        if self.head == node {
-            self.head = node.next();
+    /// Link a node to the end of every ordering except `order`
+    #[inline]
+    fn link_others_back(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        for other in (0..N).filter(|&other| other != order) {
+            self.link_back(other, node);
         }
-        if self.tail == node {
-            self.tail = node.previous();
+    }
+
+    /// Link a node, that is not linked into `order`, to the end of `order`
+    #[inline]
+    fn link_back(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        match NonNull::new(self.tail(order)) {
+            Some(tail) => unsafe { self.link_after(order, node, tail) },
+            None => self.link_only(order, node),
+        }
+    }
+
+    /// Link a node, that is not linked into `order`, to the start of `order`
+    #[inline]
+    fn link_front(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        match NonNull::new(self.head(order)) {
+            Some(head) => unsafe { self.link_before(order, node, head) },
+            None => self.link_only(order, node),
         }
-        node.remove();
     }
 
-    /// Insert a node before a different node.
+    /// Make a node the only node of the empty `order`
+    #[inline]
+    fn link_only(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        self.ends[order] = Ends {
+            head: node.as_ptr(),
+            tail: node.as_ptr(),
+        };
+        self.label_linked(order, node);
+    }
+
+    /// Link a node, that is not linked into `order`, before a different node in `order`
     ///
     /// # SAFETY
     ///
     /// `node` and `before` must not be the same node.
     #[inline]
-    pub unsafe fn insert_before(&mut self, node: NonNull<Node<K, V>>, before: NonNull<Node<K, V>>) {
-        let was_head = self.head == before.as_ptr();
-        before.as_mut().insert_before(node);
+    unsafe fn link_before(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        before: NonNull<Node<K, V, N>>,
+    ) {
+        let was_head = self.head(order) == before.as_ptr();
+        Node::link_before(order, before, node);
         if was_head {
-            self.head = node.as_ptr();
+            self.ends[order].head = node.as_ptr();
         }
+        self.label_linked(order, node);
     }
 
-    /// Insert a node after a different node.
+    /// Link a node, that is not linked into `order`, after a different node in `order`
     ///
     /// # SAFETY
     ///
     /// `node` and `after` must not be the same node.
     #[inline]
-    pub unsafe fn insert_after(&mut self, node: NonNull<Node<K, V>>, after: NonNull<Node<K, V>>) {
-        let was_tail = self.tail == after.as_ptr();
-        after.as_mut().insert_after(node);
+    unsafe fn link_after(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        after: NonNull<Node<K, V, N>>,
+    ) {
+        let was_tail = self.tail(order) == after.as_ptr();
+        Node::link_after(order, after, node);
         if was_tail {
-            self.tail = node.as_ptr();
+            self.ends[order].tail = node.as_ptr();
+        }
+        self.label_linked(order, node);
+    }
+
+    /// Unlink a node from `order` only
+    fn unlink(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        let ends = &mut self.ends[order];
+        unsafe {
+            if ends.head == node.as_ptr() {
+                ends.head = node.as_ref().next(order);
+            }
+            if ends.tail == node.as_ptr() {
+                ends.tail = node.as_ref().previous(order);
+            }
+            Node::unlink(order, node);
+        }
+    }
+}
+
+impl<K, V> LinkedList<K, V> {
+    /// Create a list from the ends of a run of nodes, that is not linked into any other list
+    #[inline]
+    fn from_ends(head: *mut Node<K, V>, tail: *mut Node<K, V>) -> Self {
+        Self {
+            ends: [Ends { head, tail }],
+        }
+    }
+
+    /// Remove the first node from the list and return it
+    pub fn pop_front(&mut self) -> Option<Box<Node<K, V>>> {
+        NonNull::new(self.head(0)).map(|node| {
+            self.remove(node);
+            unsafe { Box::from_raw(node.as_ptr()) }
+        })
+    }
+
+    /// Remove the last node from the list and return it
+    pub fn pop_back(&mut self) -> Option<Box<Node<K, V>>> {
+        NonNull::new(self.tail(0)).map(|node| {
+            self.remove(node);
+            unsafe { Box::from_raw(node.as_ptr()) }
+        })
+    }
+
+    /// Detach the run of nodes from `first` to `last` inclusively into a new list in O(1)
+    ///
+    /// # SAFETY
+    ///
+    /// `first` and `last` must belong to this list and `first` must not come after `last`.
+    pub unsafe fn detach_range(
+        &mut self,
+        first: NonNull<Node<K, V>>,
+        last: NonNull<Node<K, V>>,
+    ) -> Self {
+        let (first, last) = (first.as_ptr(), last.as_ptr());
+        let before = (*first).previous(0);
+        let after = (*last).next(0);
+        if before.is_null() {
+            self.ends[0].head = after;
+        } else {
+            (*before).links[0].next = after;
+        }
+        if after.is_null() {
+            self.ends[0].tail = before;
+        } else {
+            (*after).links[0].previous = before;
+        }
+        (*first).links[0].previous = null_mut();
+        (*last).links[0].next = null_mut();
+
+        Self::from_ends(first, last)
+    }
+
+    /// Detach all nodes after `node` into a new list in O(1)
+    ///
+    /// # SAFETY
+    ///
+    /// `node` must belong to this list.
+    pub unsafe fn split_after(&mut self, node: NonNull<Node<K, V>>) -> Self {
+        let node = node.as_ptr();
+        let next = (*node).next(0);
+        let detached = Self::from_ends(
+            next,
+            if next.is_null() {
+                null_mut()
+            } else {
+                self.tail(0)
+            },
+        );
+        if !next.is_null() {
+            (*next).links[0].previous = null_mut();
+        }
+        (*node).links[0].next = null_mut();
+        self.ends[0].tail = node;
+        detached
+    }
+
+    /// Detach all nodes before `node` into a new list in O(1)
+    ///
+    /// # SAFETY
+    ///
+    /// `node` must belong to this list.
+    pub unsafe fn split_before(&mut self, node: NonNull<Node<K, V>>) -> Self {
+        let node = node.as_ptr();
+        let previous = (*node).previous(0);
+        let detached = Self::from_ends(
+            if previous.is_null() {
+                null_mut()
+            } else {
+                self.head(0)
+            },
+            previous,
+        );
+        if !previous.is_null() {
+            (*previous).links[0].next = null_mut();
+        }
+        (*node).links[0].previous = null_mut();
+        self.ends[0].head = node;
+        detached
+    }
+
+    /// Link all nodes of `other` after the `after` node. Relinking is O(1) and relabeling the linked nodes amortized
+    /// O(m) for `m` nodes in `other`. If `after` is null, the nodes are linked to the start of the list.
+    ///
+    /// # SAFETY
+    ///
+    /// `after` must be null or belong to this list.
+    pub unsafe fn splice_after(&mut self, after: *mut Node<K, V>, mut other: Self) {
+        let (first, last) = match (NonNull::new(other.head(0)), NonNull::new(other.tail(0))) {
+            (Some(first), Some(last)) => (first.as_ptr(), last.as_ptr()),
+            _ => return,
+        };
+
+        // Prevent the nodes from being freed, when `other` is dropped
+        other.ends[0] = Ends {
+            head: null_mut(),
+            tail: null_mut(),
+        };
+
+        let before = if after.is_null() {
+            std::mem::replace(&mut self.ends[0].head, first)
+        } else {
+            std::mem::replace(&mut (*after).links[0].next, first)
+        };
+        (*first).links[0].previous = after;
+        (*last).links[0].next = before;
+        if before.is_null() {
+            self.ends[0].tail = last;
+        } else {
+            (*before).links[0].previous = last;
+        }
+
+        let mut len = 1;
+        let mut next = first;
+        while next != last {
+            next = (*next).next(0);
+            len += 1;
         }
+        self.label_run(
+            0,
+            NonNull::new_unchecked(first),
+            NonNull::new_unchecked(last),
+            len,
+        );
     }
 }
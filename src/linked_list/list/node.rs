@@ -1,12 +1,21 @@
 use std::ptr::{null_mut, NonNull};
 
-/// Linked list node containing value of type T
-pub struct Node<K, V> {
-    /// Previous node in the list
-    previous: *mut Self,
+/// Links of a [Node] in one ordering of its list
+pub struct Links<K, V, const N: usize> {
+    /// Previous node in the ordering
+    pub(super) previous: *mut Node<K, V, N>,
 
-    /// Next node in the list
-    next: *mut Self,
+    /// Next node in the ordering
+    pub(super) next: *mut Node<K, V, N>,
+
+    /// Order-maintenance label. Labels increase from head to tail of the ordering.
+    pub(super) label: u64,
+}
+
+/// Linked list node containing value of type T, linked into each of the `N` orderings of its list
+pub struct Node<K, V, const N: usize = 1> {
+    /// Links of the node in every ordering of the list
+    pub(super) links: [Links<K, V, N>; N],
 
     /// Key used for hashing
     pub key: K,
@@ -15,68 +24,119 @@ pub struct Node<K, V> {
     pub val: V,
 }
 
-impl<K, V> Node<K, V> {
-    /// Create new node pointer with both sibling nodes set to null
+impl<K, V, const N: usize> Node<K, V, N> {
+    /// Create new node pointer with all sibling nodes set to null
     #[inline]
     pub fn new(key: K, val: V) -> NonNull<Self> {
         let ptr = Box::into_raw(Box::new(Self {
             key,
             val,
-            next: null_mut(),
-            previous: null_mut(),
+            links: std::array::from_fn(|_| Links {
+                previous: null_mut(),
+                next: null_mut(),
+                label: 0,
+            }),
         }));
         unsafe { NonNull::new_unchecked(ptr) }
     }
 
-    /// Insert a node before this one
+    /// Link `node` before `this` in `order`, patching the link of the previous node.
+    ///
+    /// Links are only ever written through raw pointers, so no pointer stored in the list is derived from a
+    /// reference.
+    ///
+    /// # SAFETY
+    ///
+    /// Both pointers must be valid and distinct.
     #[inline]
-    pub(super) fn insert_before(&mut self, mut node: NonNull<Self>) {
-        unsafe {
-            node.as_mut().next = self;
-            node.as_mut().previous = self.previous;
+    pub(super) unsafe fn link_before(order: usize, this: NonNull<Self>, node: NonNull<Self>) {
+        let (this, node) = (this.as_ptr(), node.as_ptr());
+        let previous = (*this).links[order].previous;
+        (*node).links[order].next = this;
+        (*node).links[order].previous = previous;
+        if !previous.is_null() {
+            (*previous).links[order].next = node;
         }
-
-        self.previous = node.as_ptr();
+        (*this).links[order].previous = node;
     }
-    /// Insert a node after this one
+
+    /// Link `node` after `this` in `order`, patching the link of the next node.
+    ///
+    /// # SAFETY
+    ///
+    /// Both pointers must be valid and distinct.
     #[inline]
-    pub(super) fn insert_after(&mut self, mut node: NonNull<Self>) {
-        unsafe {
-            node.as_mut().next = self.next;
-            node.as_mut().previous = self;
+    pub(super) unsafe fn link_after(order: usize, this: NonNull<Self>, node: NonNull<Self>) {
+        let (this, node) = (this.as_ptr(), node.as_ptr());
+        let next = (*this).links[order].next;
+        (*node).links[order].next = next;
+        (*node).links[order].previous = this;
+        if !next.is_null() {
+            (*next).links[order].previous = node;
         }
+        (*this).links[order].next = node;
+    }
 
-        self.next = node.as_ptr();
+    /// Return pointer to the previous node in `order`. Can be null.
+    #[inline]
+    pub fn previous(&self, order: usize) -> *mut Self {
+        self.links[order].previous
     }
 
-    /// Return pointer to the previous node. Can be null.
+    /// Return pointer to the next node in `order`. Can be null.
     #[inline]
-    pub fn previous(&self) -> *mut Self {
-        self.previous
+    pub fn next(&self, order: usize) -> *mut Self {
+        self.links[order].next
     }
 
-    /// Return pointer to the next node. Can be null.
+    /// Read the link to the previous node of `this` in `order` without creating a reference to the whole node,
+    /// which would invalidate outstanding mutable references to its value. Returns null, if `this` is null.
+    ///
+    /// # SAFETY
+    ///
+    /// `this` must be null or valid.
     #[inline]
-    pub fn next(&self) -> *mut Self {
-        self.next
+    pub unsafe fn previous_of(this: *mut Self, order: usize) -> *mut Self {
+        if this.is_null() {
+            this
+        } else {
+            (*this).links[order].previous
+        }
     }
 
-    /// Remove node from the list, patching the previous and next values on the neighboring nodes.
+    /// Read the link to the next node of `this` in `order` without creating a reference to the whole node, which
+    /// would invalidate outstanding mutable references to its value. Returns null, if `this` is null.
     ///
-    /// The pointers on the removed node are left as is, as they would be set as needed later on.
+    /// # SAFETY
+    ///
+    /// `this` must be null or valid.
     #[inline]
-    pub(super) fn remove(&mut self) {
-        unsafe {
-            if let Some(next) = self.next.as_mut() {
-                next.previous = self.previous;
-            }
-            if let Some(previous) = self.previous.as_mut() {
-                previous.next = self.next;
-            }
+    pub unsafe fn next_of(this: *mut Self, order: usize) -> *mut Self {
+        if this.is_null() {
+            this
+        } else {
+            (*this).links[order].next
+        }
+    }
+
+    /// Unlink `this` from `order`, patching the previous and next values on the neighboring nodes.
+    ///
+    /// # SAFETY
+    ///
+    /// `this` must be valid.
+    #[inline]
+    pub(super) unsafe fn unlink(order: usize, this: NonNull<Self>) {
+        let this = this.as_ptr();
+        let Links { previous, next, .. } = (*this).links[order];
+        if !next.is_null() {
+            (*next).links[order].previous = previous;
+        }
+        if !previous.is_null() {
+            (*previous).links[order].next = next;
         }
 
         // Erase links of removed node, just to be safe
-        self.next = null_mut();
-        self.previous = null_mut();
+        (*this).links[order].next = null_mut();
+        (*this).links[order].previous = null_mut();
     }
 }
@@ -0,0 +1,157 @@
+//! Order-maintenance labels for O(1) comparison of node positions.
+//!
+//! Every node carries an integer label in each ordering, that increases from head to tail of the ordering. A newly
+//! linked run of nodes is labeled evenly in the gap between the labels of its neighbours. If the gap is too small,
+//! the smallest enclosing aligned label range with a low enough node density is evenly relabeled, as described in
+//! "Two Simplified Algorithms for Maintaining Order in a List" by Bender et al. This keeps single node insertions
+//! amortized O(log n).
+//!
+//! Labels are kept valid by every operation relinking nodes, so comparing positions never has to relabel.
+
+use super::{LinkedList, Node};
+use std::{cmp::Ordering, ptr::NonNull};
+
+/// Number of bits in the label space
+const LABEL_BITS: u32 = 62;
+
+/// Exclusive upper bound of labels
+pub(super) const LABEL_LIMIT: u64 = 1 << LABEL_BITS;
+
+/// A label range of size `2^i` is relabeled, only if it contains no more than `2^i / DENSITY^i` nodes
+const DENSITY: f64 = 1.3;
+
+impl<K, V, const N: usize> LinkedList<K, V, N> {
+    /// Compare the positions of two nodes in `order` in O(1)
+    ///
+    /// # SAFETY
+    ///
+    /// `a` and `b` must belong to this list.
+    pub unsafe fn cmp_position(
+        &self,
+        order: usize,
+        a: NonNull<Node<K, V, N>>,
+        b: NonNull<Node<K, V, N>>,
+    ) -> Ordering {
+        a.as_ref().links[order]
+            .label
+            .cmp(&b.as_ref().links[order].label)
+    }
+
+    /// Assign a label in `order` to a node, that was just linked into `order`
+    #[inline]
+    pub(super) fn label_linked(&mut self, order: usize, node: NonNull<Node<K, V, N>>) {
+        unsafe { self.label_run(order, node, node, 1) };
+    }
+
+    /// Assign labels in `order` to the run of `len` nodes from `first` to `last`, that was just linked into `order`
+    ///
+    /// # SAFETY
+    ///
+    /// `first` and `last` must belong to this list and be `len` nodes apart inclusively in `order`.
+    pub(super) unsafe fn label_run(
+        &mut self,
+        order: usize,
+        first: NonNull<Node<K, V, N>>,
+        last: NonNull<Node<K, V, N>>,
+        len: usize,
+    ) {
+        let low = match (*first.as_ptr()).previous(order).as_ref() {
+            Some(previous) => previous.links[order].label + 1,
+            None => 0,
+        };
+        let high = match (*last.as_ptr()).next(order).as_ref() {
+            Some(next) => next.links[order].label,
+            None => LABEL_LIMIT,
+        };
+
+        if high - low >= len as u64 {
+            spread(order, first.as_ptr(), len, low, high - low);
+        } else {
+            self.relabel_range(order, first, last, len);
+        }
+    }
+
+    /// Evenly relabel the smallest aligned label range around a newly linked run of nodes, that is sparse enough
+    unsafe fn relabel_range(
+        &mut self,
+        order: usize,
+        first: NonNull<Node<K, V, N>>,
+        last: NonNull<Node<K, V, N>>,
+        len: usize,
+    ) {
+        let before = (*first.as_ptr()).previous(order);
+        let after = (*last.as_ptr()).next(order);
+        let base = match before.as_ref() {
+            Some(previous) => previous.links[order].label,
+            None => 0,
+        };
+
+        for bits in 1..=LABEL_BITS {
+            let size = 1u64 << bits;
+            let low = base & !(size - 1);
+            let high = low + size;
+
+            // Nodes with labels in range are contiguous in the list, so walk outwards from the new run
+            let mut start = first.as_ptr();
+            let mut count = len;
+            let mut previous = before;
+            while let Some(p) = previous.as_ref() {
+                if p.links[order].label < low {
+                    break;
+                }
+                start = previous;
+                count += 1;
+                previous = p.previous(order);
+            }
+            let mut next = after;
+            while let Some(p) = next.as_ref() {
+                if p.links[order].label >= high {
+                    break;
+                }
+                count += 1;
+                next = p.next(order);
+            }
+
+            if bits == LABEL_BITS || count as f64 <= size as f64 / DENSITY.powi(bits as i32) {
+                spread(order, start, count, low, size);
+                return;
+            }
+        }
+    }
+
+    /// Evenly relabel all nodes of `order` in O(n)
+    pub(super) fn relabel_all(&mut self, order: usize) {
+        let mut count = 0;
+        let mut next = self.head(order);
+        while let Some(node) = unsafe { next.as_ref() } {
+            count += 1;
+            next = node.next(order);
+        }
+
+        if count != 0 {
+            unsafe { spread(order, self.head(order), count, 0, LABEL_LIMIT) };
+        }
+    }
+}
+
+/// Evenly label `count` nodes of `order` starting at `first` within the `size` labels starting at `low`
+///
+/// # SAFETY
+///
+/// There must be at least `count` nodes in `order` starting at `first` and `size` must not be less than `count`.
+unsafe fn spread<K, V, const N: usize>(
+    order: usize,
+    first: *mut Node<K, V, N>,
+    count: usize,
+    low: u64,
+    size: u64,
+) {
+    let gap = size / count as u64;
+    let mut label = low + gap / 2;
+    let mut current = first;
+    for _ in 0..count {
+        (*current).links[order].label = label;
+        label += gap;
+        current = (*current).next(order);
+    }
+}
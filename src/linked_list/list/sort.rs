@@ -0,0 +1,150 @@
+//! In-place bottom-up merge sort of the list links.
+//!
+//! Runs of doubled length are merged pass by pass by moving single nodes of the right run in front of nodes of the
+//! left run. Every move leaves the list fully linked, so a panicking comparison can only leave the list partially
+//! sorted, but never broken. No nodes are allocated and only O(1) extra memory is used.
+
+use super::{LinkedList, Node};
+use std::{cmp::Ordering, ptr::NonNull};
+
+/// Relabels all nodes of an ordering, when dropped. Sorting relinks nodes without labeling them, so this restores
+/// the labels once it is done, even if it panicked.
+struct RelabelOnDrop<'a, K, V, const N: usize>(&'a mut LinkedList<K, V, N>, usize);
+
+impl<K, V, const N: usize> Drop for RelabelOnDrop<'_, K, V, N> {
+    fn drop(&mut self) {
+        self.0.relabel_all(self.1);
+    }
+}
+
+impl<K, V, const N: usize> LinkedList<K, V, N> {
+    /// Stably sort `order` in place in O(n log n) by relinking its nodes. No nodes are reallocated.
+    ///
+    /// If `cmp` panics, the list stays structurally valid, but can be partially sorted.
+    pub fn sort_by<F>(&mut self, order: usize, cmp: F)
+    where
+        F: FnMut(&Node<K, V, N>, &Node<K, V, N>) -> Ordering,
+    {
+        RelabelOnDrop(self, order).0.merge_sort(order, cmp);
+    }
+
+    /// Stably sort `order` in place by a key, that is computed only once per node.
+    ///
+    /// The keys are cached in a buffer in the current order. Until sorting is done, every node's order label holds
+    /// the index of its key in the buffer, as the labels are reassigned afterwards anyway.
+    pub fn sort_by_cached_key<T, F>(&mut self, order: usize, mut sort_key: F)
+    where
+        T: Ord,
+        F: FnMut(&Node<K, V, N>) -> T,
+    {
+        let list = RelabelOnDrop(self, order);
+
+        let mut keys = Vec::new();
+        let mut next = list.0.head(order);
+        while !next.is_null() {
+            unsafe {
+                (*next).links[order].label = keys.len() as u64;
+                keys.push(sort_key(&*next));
+                next = (*next).next(order);
+            }
+        }
+
+        list.0.merge_sort(order, |a, b| {
+            keys[a.links[order].label as usize].cmp(&keys[b.links[order].label as usize])
+        });
+    }
+
+    /// Run the merge passes over all of `order`, leaving its order labels unassigned
+    fn merge_sort<F>(&mut self, order: usize, mut cmp: F)
+    where
+        F: FnMut(&Node<K, V, N>, &Node<K, V, N>) -> Ordering,
+    {
+        let len = self.count(order);
+        let mut width = 1;
+        while width < len {
+            let mut start = self.head(order);
+            while !start.is_null() {
+                start = unsafe { self.merge_runs(order, start, width, &mut cmp) };
+            }
+            width *= 2;
+        }
+    }
+    /// Merge the sorted run of up to `width` nodes starting at `start` with the run of up to `width` nodes following
+    /// it in `order`. Returns the first node after the merged runs, or null, if the end of `order` was reached.
+    ///
+    /// # SAFETY
+    ///
+    /// `start` must belong to this list.
+    unsafe fn merge_runs<F>(
+        &mut self,
+        order: usize,
+        start: *mut Node<K, V, N>,
+        width: usize,
+        cmp: &mut F,
+    ) -> *mut Node<K, V, N>
+    where
+        F: FnMut(&Node<K, V, N>, &Node<K, V, N>) -> Ordering,
+    {
+        let mut left_len = 0;
+        let mut right = start;
+        while left_len < width && !right.is_null() {
+            right = (*right).next(order);
+            left_len += 1;
+        }
+
+        let mut left = start;
+        let mut right_len = width;
+        while left_len > 0 && right_len > 0 && !right.is_null() {
+            // Only take from the right run on strictly less, to keep the sort stable
+            if cmp(&*right, &*left) == Ordering::Less {
+                let next = (*right).next(order);
+                self.relink_before(
+                    order,
+                    NonNull::new_unchecked(right),
+                    NonNull::new_unchecked(left),
+                );
+                right = next;
+                right_len -= 1;
+            } else {
+                left = (*left).next(order);
+                left_len -= 1;
+            }
+        }
+
+        // Skip the rest of the right run, which is already in place
+        while right_len > 0 && !right.is_null() {
+            right = (*right).next(order);
+            right_len -= 1;
+        }
+        right
+    }
+
+    /// Move `node` right before the different node `anchor` in `order`, without touching the order labels
+    ///
+    /// # SAFETY
+    ///
+    /// Both nodes must belong to this list and be distinct.
+    unsafe fn relink_before(
+        &mut self,
+        order: usize,
+        node: NonNull<Node<K, V, N>>,
+        anchor: NonNull<Node<K, V, N>>,
+    ) {
+        self.unlink(order, node);
+        Node::link_before(order, anchor, node);
+        if self.head(order) == anchor.as_ptr() {
+            self.ends[order].head = node.as_ptr();
+        }
+    }
+
+    /// Count the nodes of `order` in O(n)
+    fn count(&self, order: usize) -> usize {
+        let mut count = 0;
+        let mut next = self.head(order);
+        while let Some(node) = unsafe { next.as_ref() } {
+            count += 1;
+            next = node.next(order);
+        }
+        count
+    }
+}
@@ -1,7 +1,7 @@
-mod tests;
+pub(crate) mod tests;
 
 pub(crate) mod list;
 pub(crate) use list::LinkedList;
 
 mod cursor;
-pub use cursor::{Cursor, CursorMut};
+pub use cursor::{Cursor, CursorMut, InsertOutcome};
@@ -1,178 +1,1050 @@
 #![cfg(test)]
 
-// use super::LinkedList;
-// use crate::alloc::linked_list::node::Node;
-// use std::{collections::VecDeque, fmt::Debug, ptr::null_mut};
-
-// // Generate tests with various node sizes
-// macro_rules! gen_tests {
-//     ($name:ident) => {
-//         mod $name {
-//             // Start with 4, so the first `Run Test` in the IDE is
-//             // has multiple values per node
-//             gen_tests! {@for_sizes $name {4 1 2 8 15 16 17 32 64 128}}
-//         }
-//     };
-//     (@for_sizes $name:ident { $( $size:literal )* }) => {
-//         $(
-//             paste::paste! {
-//                 #[test]
-//                 fn [<size_ $size>]() {
-//                     super::$name::<$size>();
-//                 }
-//             }
-//         )*
-//     };
-// }
-
-// gen_tests! {test_linear_inserts}
-// fn test_linear_inserts<const N: usize>() {
-//     let mut std = VecDeque::new();
-//     let mut ll = LinkedList::<usize, N>::new();
-//     let mut c = ll.cursor_mut();
-//     for i in 0..256 {
-//         c.next();
-//         c.insert_after(i);
-//         validate(&mut c.list);
-
-//         std.push_back(i);
-//         compare(&std, &mut c.list);
-//     }
-// }
-
-// gen_tests! {test_collect}
-// fn test_collect<const N: usize>() {
-//     let src = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
-//     macro_rules! copy {
-//         () => {
-//             src.iter().cloned().collect()
-//         };
-//     }
-//     let std: VecDeque<usize> = copy!();
-//     let mut ll: LinkedList<usize, N> = copy!();
-//     validate(&mut ll);
-//     compare(&std, &mut ll);
-// }
-
-// gen_tests! {test_insert_before}
-// fn test_insert_before<const N: usize>() {
-//     let mut std = VecDeque::<usize>::new();
-//     let mut ll = LinkedList::<usize, N>::new();
-//     let mut c = ll.cursor_mut();
-//     let mut pos = 0;
-
-//     // Keep inserting before the middle node
-//     for i in 0..256 {
-//         let mid = i / 2;
-
-//         // Keep the cursor right after the middle node
-//         if i != 0 {
-//             while pos != mid + 1 {
-//                 pos -= 1;
-//                 c.previous();
-//             }
-//         }
-
-//         c.insert_before(i);
-//         pos += 1;
-
-//         validate(&mut c.list);
-
-//         std.insert(mid, i);
-//         compare(&std, &mut c.list);
-//     }
-// }
-
-// gen_tests! {test_insert_after}
-// fn test_insert_after<const N: usize>() {
-//     let mut std = VecDeque::<usize>::new();
-//     let mut ll = LinkedList::<usize, N>::new();
-//     let mut c = ll.cursor_mut();
-//     let mut pos = 0;
-
-//     // Keep inserting after the middle node
-//     for i in 0..256 {
-//         let mid = i / 2;
-
-//         // Keep the cursor on the middle node
-//         while pos != mid {
-//             pos += 1;
-//             c.next();
-//         }
-
-//         c.insert_after(i);
-
-//         validate(&mut c.list);
-
-//         std.insert(if i == 0 { 0 } else { mid + 1 }, i);
-//         compare(&std, &mut c.list);
-//     }
-// }
-
-// // TODO: seeking tests
-// // TODO: various removal tests
-// // TODO: fuzzing test with no references
-// // TODO: fuzzing test with references
-// // TODO: 100% coverage
-
-// /// Validate the various components of the list are consistent with each other
-// fn validate<T, const N: usize>(ll: &mut LinkedList<T, N>)
-// where
-//     T: Sized + Clone + Eq + Debug + 'static,
-// {
-//     let mut iterations = 0;
-//     let mut c = ll.cursor_mut();
-//     if c.value().is_some() {
-//         iterations = 1;
-//     }
-//     while c.next() {
-//         iterations += 1;
-//     }
-//     assert_eq!(iterations, ll.len());
-
-//     let it_len = ll.iter_mut().len();
-//     assert_eq!(it_len, ll.len());
-
-//     assert_ne!(ll.head, null_mut());
-//     assert_ne!(ll.tail, null_mut());
-//     if ll.len() == 0 {
-//         assert_eq!(ll.tail, ll.head);
-//     }
-
-//     let mut node_length = 0;
-//     let mut node = ll.head;
-//     let mut prev: *mut Node<T, N> = null_mut();
-//     while node != null_mut() {
-//         unsafe {
-//             node_length += (*node).len();
-
-//             if prev != null_mut() {
-//                 assert_eq!((*prev).next(), node);
-//             }
-//             assert_eq!((*node).previous(), prev);
-//             prev = node;
-
-//             node = (*node).next();
-//         }
-//     }
-//     assert_eq!(node_length, ll.len());
-// }
-
-// /// Assert the list from standard library and allocator list are equal.
-// // Also perform basic consistency validation.
-// fn compare<T, const N: usize>(std: &VecDeque<T>, ll: &mut LinkedList<T, N>)
-// where
-//     T: Sized + Clone + Eq + Debug + 'static,
-// {
-//     macro_rules! compare_it {
-//         ($expected:expr, $got:expr) => {
-//             assert_eq!(
-//                 $expected.cloned().collect::<Vec<_>>(),
-//                 $got.map(|v| v.clone()).collect::<Vec<_>>(),
-//             );
-//         };
-//     }
-
-//     compare_it!(std.iter(), ll.iter_mut());
-//     compare_it!(std.iter().rev(), ll.iter_mut_reverse());
-// }
+use crate::{
+    test_support::{compare, compare_cursor},
+    DefaultHashBuilder, InsertOutcome, LinkedMap, RekeyError, ScanFrom, SplicePolicy,
+};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
+};
+
+// Generate a test per seed. Shared with the test modules of the other map types.
+macro_rules! gen_tests {
+    ($name:ident) => {
+        gen_tests! {@for_seeds $name {1 2 3 7 42 1337 65535 9001}}
+    };
+    (@for_seeds $name:ident { $( $seed:literal )* }) => {
+        mod $name {
+            $(
+                paste::paste! {
+                    #[test]
+                    fn [<seed_ $seed>]() {
+                        super::$name($seed);
+                    }
+                }
+            )*
+        }
+    };
+}
+pub(crate) use gen_tests;
+
+/// Scale down the number of random steps under Miri, which is orders of magnitude slower
+pub(crate) const fn steps(n: usize) -> usize {
+    if cfg!(miri) {
+        n / 20
+    } else {
+        n
+    }
+}
+
+/// Small xorshift generator, so runs are reproducible per seed
+pub(crate) struct Rng(pub(crate) u64);
+
+impl Rng {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    pub(crate) fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+}
+
+type Model = crate::test_support::Model<u32, u32>;
+
+gen_tests! {test_map_operations}
+fn test_map_operations(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = LinkedMap::new();
+    let mut model = Model::default();
+
+    for _ in 0..steps(2000) {
+        let k = rng.below(64) as u32;
+        let v = rng.next() as u32;
+        match rng.below(4) {
+            0 => assert_eq!(map.prepend(k, v), model.prepend(k, v)),
+            1 => assert_eq!(map.append(k, v), model.append(k, v)),
+            2 => assert_eq!(map.remove(&k), model.remove(k)),
+            _ => {
+                if let Some(mut c) = map.cursor_at_mut(&k) {
+                    c.save();
+                    model.saved = Some(k);
+                }
+            }
+        }
+        compare(&map, &model);
+    }
+}
+
+gen_tests! {test_cursor_operations}
+fn test_cursor_operations(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = LinkedMap::new();
+    let mut model = Model::default();
+
+    for _ in 0..steps(100) {
+        // Map level operations between cursor sessions
+        for _ in 0..rng.below(8) {
+            let k = rng.below(32) as u32;
+            let v = rng.next() as u32;
+            match rng.below(3) {
+                0 => assert_eq!(map.prepend(k, v), model.prepend(k, v)),
+                1 => assert_eq!(map.append(k, v), model.append(k, v)),
+                _ => assert_eq!(map.remove(&k), model.remove(k)),
+            }
+            compare(&map, &model);
+        }
+
+        // Long-lived cursor, starting at the saved node, if any
+        let mut c = match map.resume_mut() {
+            Some(c) => {
+                model.cursor = model.position(model.saved.unwrap()).unwrap();
+                c
+            }
+            None => {
+                model.cursor = 0;
+                map.cursor_mut()
+            }
+        };
+        compare_cursor(&c, &model);
+
+        for _ in 0..rng.below(32) {
+            let k = rng.below(32) as u32;
+            let v = rng.next() as u32;
+            match rng.below(10) {
+                0 => {
+                    let moves = model.cursor + 1 < model.order.len();
+                    assert_eq!(c.next().is_some(), moves);
+                    if moves {
+                        model.cursor += 1;
+                    }
+                }
+                1 => {
+                    let moves = model.cursor > 0 && !model.order.is_empty();
+                    assert_eq!(c.previous().is_some(), moves);
+                    if moves {
+                        model.cursor -= 1;
+                    }
+                }
+                2 => {
+                    c.to_front();
+                    model.cursor = 0;
+                }
+                3 => {
+                    c.to_back();
+                    model.cursor = model.order.len().saturating_sub(1);
+                }
+                4 => assert_eq!(
+                    c.insert_before(k, v),
+                    model.insert_at_cursor(k, v, false, true)
+                ),
+                5 => assert_eq!(
+                    c.insert_after(k, v),
+                    model.insert_at_cursor(k, v, true, true)
+                ),
+                6 => assert_eq!(c.remove(), model.remove_and_retreat()),
+                7 => {
+                    c.move_to_front();
+                    if let Some(k) = model.cursor_key() {
+                        model.order.remove(model.cursor);
+                        model.order.push_front(k);
+                        model.cursor = 0;
+                    }
+                }
+                8 => {
+                    c.move_to_back();
+                    if let Some(k) = model.cursor_key() {
+                        model.order.remove(model.cursor);
+                        model.order.push_back(k);
+                        model.cursor = model.order.len() - 1;
+                    }
+                }
+                _ => {
+                    c.save();
+                    model.saved = model.cursor_key();
+                }
+            }
+            compare_cursor(&c, &model);
+        }
+
+        compare(&map, &model);
+    }
+}
+
+gen_tests! {test_save_resume}
+fn test_save_resume(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map: LinkedMap<u32, u32> = (0..32).map(|i| (i, i)).collect();
+    let mut model = Model::default();
+    for i in 0..32 {
+        model.append(i, i);
+    }
+
+    for _ in 0..steps(500) {
+        let k = rng.below(48) as u32;
+        match rng.below(4) {
+            0 => {
+                if let Some(mut c) = map.cursor_at_mut(&k) {
+                    c.save();
+                    model.saved = Some(k);
+                }
+            }
+            1 => assert_eq!(map.remove(&k), model.remove(k)),
+            2 => assert_eq!(map.append(k, k), model.append(k, k)),
+            _ => {
+                map.clear_saved();
+                model.saved = None;
+            }
+        }
+        compare(&map, &model);
+
+        if let Some(mut c) = map.resume_mut() {
+            assert_eq!(c.key().copied(), model.saved);
+            model.cursor = model.position(model.saved.unwrap()).unwrap();
+            assert_eq!(c.remove_and_advance().map(|(k, _)| k), model.saved);
+            model.remove(model.cursor_key().unwrap());
+            assert!(map.resume().is_none());
+            compare(&map, &model);
+        }
+    }
+}
+
+gen_tests! {test_bulk_operations}
+fn test_bulk_operations(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map: LinkedMap<u32, u32> = LinkedMap::new();
+    let mut model = Model::default();
+
+    for _ in 0..steps(300) {
+        let a = rng.below(48) as u32;
+        let b = rng.below(48) as u32;
+        match rng.below(10) {
+            0 => {
+                // Hold all value references at once, before writing through any of them
+                let values: Vec<_> = map.iter_mut().map(|(_, v)| v).collect();
+                for v in values {
+                    *v = v.wrapping_add(1);
+                }
+                model
+                    .values
+                    .values_mut()
+                    .for_each(|v| *v = v.wrapping_add(1));
+            }
+            1 => {
+                let values: Vec<_> = map.iter_rev_mut().map(|(_, v)| v).collect();
+                for v in values {
+                    *v = v.wrapping_mul(3);
+                }
+                model
+                    .values
+                    .values_mut()
+                    .for_each(|v| *v = v.wrapping_mul(3));
+            }
+            2 => {
+                let range = map.range_mut(&a, &b);
+                let (start, end) = (model.position(a), model.position(b));
+                assert_eq!(range.is_some(), start.is_some() && end.is_some());
+                if let Some(range) = range {
+                    let values: Vec<_> = range.map(|(_, v)| v).collect();
+                    for v in values {
+                        *v = v.wrapping_add(7);
+                    }
+                    for i in start.unwrap()..end.unwrap().max(start.unwrap()) {
+                        let v = model.values.get_mut(&model.order[i]).unwrap();
+                        *v = v.wrapping_add(7);
+                    }
+                }
+            }
+            3 => {
+                // Few distinct sort keys, so the stability of the sort is checked
+                match rng.below(3) {
+                    0 => map.sort_by_key(|_, v| v % 8),
+                    1 => map.sort_by_cached_key(|_, v| v % 8),
+                    _ => map.sort_by(|_, a, _, b| (a % 8).cmp(&(b % 8))),
+                }
+                let values = &model.values;
+                model.order.make_contiguous().sort_by_key(|k| values[k] % 8);
+            }
+            4 => {
+                map.reverse();
+                model.order.make_contiguous().reverse();
+            }
+            5 => {
+                let n = rng.below(model.order.len() as u64 + 1) as usize;
+                map.rotate_left(n);
+                model.order.rotate_left(n);
+            }
+            6 => {
+                // Move the tail starting at `a` to the front through a second map
+                let mut tail = map.split_off(&a);
+                if let Some(i) = model.position(a) {
+                    assert_eq!(tail.len(), model.order.len() - i);
+                    model.order.rotate_left(i);
+                } else {
+                    assert!(tail.is_empty());
+                }
+                assert_eq!(tail.check_invariants(), Ok(()));
+                map.prepend_map(&mut tail);
+                assert!(tail.is_empty());
+            }
+            7 => {
+                let drained = map.drain_range(&a, &b).map(|d| d.collect::<Vec<_>>());
+                let (start, end) = (model.position(a), model.position(b));
+                assert_eq!(drained.is_some(), start.is_some() && end.is_some());
+                if let Some(drained) = drained {
+                    let expected: Vec<_> = model
+                        .order
+                        .drain(start.unwrap()..end.unwrap().max(start.unwrap()))
+                        .collect();
+                    let expected: Vec<_> = expected
+                        .into_iter()
+                        .map(|k| (k, model.values.remove(&k).unwrap()))
+                        .collect();
+                    assert_eq!(drained, expected);
+                }
+            }
+            8 => {
+                for _ in 0..rng.below(8) {
+                    let k = rng.below(48) as u32;
+                    let v = rng.next() as u32;
+                    assert_eq!(map.append(k, v), model.append(k, v));
+                }
+            }
+            _ => {
+                if a != b {
+                    let moved = map.move_before(&a, &b);
+                    assert_eq!(
+                        moved,
+                        model.position(a).is_some() && model.position(b).is_some()
+                    );
+                    if moved {
+                        model.order.remove(model.position(a).unwrap());
+                        model.order.insert(model.position(b).unwrap(), a);
+                    }
+                }
+            }
+        }
+        compare(&map, &model);
+    }
+}
+
+gen_tests! {test_swap}
+fn test_swap(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = LinkedMap::new();
+    let mut model = Model::default();
+
+    for _ in 0..steps(1000) {
+        // Few keys, so swaps of adjacent nodes and of the head and tail are frequent
+        let a = rng.below(10) as u32;
+        let b = rng.below(10) as u32;
+        match rng.below(6) {
+            0 => assert_eq!(map.append(a, a), model.append(a, a)),
+            1 => {
+                if let Some(mut c) = map.cursor_at_mut(&a) {
+                    c.save();
+                    model.saved = Some(a);
+                }
+            }
+            2 | 3 => {
+                let positions = (model.position(a), model.position(b));
+                assert_eq!(
+                    map.swap(&a, &b),
+                    positions.0.is_some() && positions.1.is_some()
+                );
+                if let (Some(i), Some(j)) = positions {
+                    model.order.swap(i, j);
+                }
+            }
+            _ => {
+                let Some(mut c) = map.cursor_at_mut(&a) else {
+                    continue;
+                };
+                let i = model.position(a).unwrap();
+                let j = match rng.below(2) {
+                    0 => {
+                        let swapped = c.swap_with_previous();
+                        assert_eq!(swapped, i > 0);
+                        swapped.then(|| i - 1)
+                    }
+                    _ => {
+                        let swapped = c.swap_with_next();
+                        assert_eq!(swapped, i + 1 < model.order.len());
+                        swapped.then_some(i + 1)
+                    }
+                };
+                if let Some(j) = j {
+                    model.order.swap(i, j);
+                }
+                model.cursor = model.position(a).unwrap();
+                compare_cursor(&c, &model);
+            }
+        }
+        compare(&map, &model);
+    }
+}
+
+/// Rekey the entry of `old` in the model, returning the expected result
+fn rekey_model(model: &mut Model, old: u32, new: u32) -> Result<(), RekeyError<u32>> {
+    let Some(i) = model.position(old) else {
+        return Err(RekeyError::NotFound(new));
+    };
+    if new != old && model.values.contains_key(&new) {
+        return Err(RekeyError::Occupied(new));
+    }
+
+    let v = model.values.remove(&old).unwrap();
+    model.values.insert(new, v);
+    model.order[i] = new;
+    if model.saved == Some(old) {
+        model.saved = Some(new);
+    }
+    Ok(())
+}
+
+gen_tests! {test_rekey}
+fn test_rekey(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = LinkedMap::new();
+    let mut model = Model::default();
+
+    for _ in 0..steps(1000) {
+        // Few keys, so renaming to a key already in the map is frequent
+        let old = rng.below(12) as u32;
+        let new = rng.below(12) as u32;
+        match rng.below(6) {
+            0 | 1 => {
+                let v = rng.next() as u32;
+                assert_eq!(map.append(old, v), model.append(old, v));
+            }
+            2 => {
+                if let Some(mut c) = map.cursor_at_mut(&old) {
+                    c.save();
+                    model.saved = Some(old);
+                }
+            }
+            3 => assert_eq!(map.rekey(&old, new), rekey_model(&mut model, old, new)),
+            _ => {
+                let Some(mut c) = map.cursor_at_mut(&old) else {
+                    continue;
+                };
+                let expected = rekey_model(&mut model, old, new).map(|()| old);
+                assert_eq!(c.replace_key(new), expected);
+                let at = if expected.is_ok() { new } else { old };
+                model.cursor = model.position(at).unwrap();
+                compare_cursor(&c, &model);
+            }
+        }
+        compare(&map, &model);
+    }
+}
+
+/// Build a map of random keys, with a random saved node, and its model
+fn random_map(rng: &mut Rng) -> (LinkedMap<u32, u32>, Model) {
+    let mut map = LinkedMap::new();
+    let mut model = Model::default();
+    for _ in 0..rng.below(12) {
+        let k = rng.below(24) as u32;
+        let v = rng.next() as u32;
+        assert_eq!(map.append(k, v), model.append(k, v));
+    }
+    if let Some(&k) = model.order.get(rng.below(24) as usize) {
+        map.cursor_at_mut(&k).unwrap().save();
+        model.saved = Some(k);
+    }
+    (map, model)
+}
+
+/// Splice `other` into `model` after the key `after`, or at the front, if [None]
+fn splice_model(
+    model: &mut Model,
+    other: &mut Model,
+    mut after: Option<u32>,
+    policy: SplicePolicy,
+) -> Result<(), u32> {
+    if policy == SplicePolicy::Error {
+        if let Some(&k) = other.order.iter().find(|k| model.values.contains_key(k)) {
+            return Err(k);
+        }
+    }
+
+    let mut run = Vec::new();
+    for k in other.order.clone() {
+        if let Some(i) = model.position(k) {
+            if policy == SplicePolicy::Skip {
+                continue;
+            }
+            if after == Some(k) {
+                after = i.checked_sub(1).map(|i| model.order[i]);
+            }
+            model.order.remove(i);
+        }
+        model.values.insert(k, other.values[&k]);
+        if other.saved == Some(k) && model.saved.is_none() {
+            model.saved = Some(k);
+        }
+        other.remove(k);
+        run.push(k);
+    }
+    let at = after.map_or(0, |k| model.position(k).unwrap() + 1);
+    for (i, k) in run.into_iter().enumerate() {
+        model.order.insert(at + i, k);
+    }
+    Ok(())
+}
+
+gen_tests! {test_splice}
+fn test_splice(seed: u64) {
+    let mut rng = Rng(seed);
+    let policies = [
+        SplicePolicy::Error,
+        SplicePolicy::Overwrite,
+        SplicePolicy::Skip,
+    ];
+
+    for _ in 0..steps(300) {
+        let (mut map, mut model) = random_map(&mut rng);
+        let (mut other, mut other_model) = random_map(&mut rng);
+        let policy = policies[rng.below(3) as usize];
+        let anchor = model.order.get(rng.below(12) as usize).copied();
+        match rng.below(5) {
+            0 => {
+                map.append_map(&mut other);
+                let after = model.order.back().copied();
+                splice_model(&mut model, &mut other_model, after, SplicePolicy::Overwrite).unwrap();
+            }
+            1 => {
+                map.prepend_map(&mut other);
+                splice_model(&mut model, &mut other_model, None, SplicePolicy::Overwrite).unwrap();
+            }
+            2 => {
+                let result = match anchor {
+                    Some(k) => map
+                        .cursor_at_mut(&k)
+                        .unwrap()
+                        .splice_after(&mut other, policy),
+                    None => map.cursor_mut().splice_after(&mut other, policy),
+                };
+                let after = anchor.or(model.order.front().copied());
+                let expected = splice_model(&mut model, &mut other_model, after, policy);
+                assert_eq!(result.map_err(|e| e.key), expected);
+            }
+            3 => {
+                let result = match anchor {
+                    Some(k) => map
+                        .cursor_at_mut(&k)
+                        .unwrap()
+                        .splice_before(&mut other, policy),
+                    None => map.cursor_mut().splice_before(&mut other, policy),
+                };
+                let before = anchor.or(model.order.front().copied());
+                let after = before
+                    .and_then(|k| model.position(k).unwrap().checked_sub(1))
+                    .map(|i| model.order[i]);
+                let expected = splice_model(&mut model, &mut other_model, after, policy);
+                assert_eq!(result.map_err(|e| e.key), expected);
+            }
+            _ => {
+                // Splitting off and appending back must keep the saved node
+                if let Some(k) = anchor {
+                    let mut tail = map.split_off(&k);
+                    map.append_map(&mut tail);
+                    assert!(tail.is_empty());
+                }
+            }
+        }
+        compare(&map, &model);
+        compare(&other, &other_model);
+    }
+}
+
+gen_tests! {test_sort_panic}
+fn test_sort_panic(seed: u64) {
+    let mut rng = Rng(seed);
+
+    for _ in 0..steps(100) {
+        let (mut map, model) = random_map(&mut rng);
+        let panic_at = rng.below(16);
+        let mut calls = 0;
+        let mut count = || {
+            calls += 1;
+            if calls > panic_at {
+                panic!("comparator panicked");
+            }
+        };
+
+        let method = rng.below(4);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| match method {
+            0 => map.sort_by(|a, _, b, _| {
+                count();
+                a.cmp(b)
+            }),
+            1 => map.sort_by_key(|k, _| {
+                count();
+                *k
+            }),
+            2 => map.sort_by_cached_key(|k, _| {
+                count();
+                *k
+            }),
+            _ => map.sort_unstable_by(|a, _, b, _| {
+                count();
+                a.cmp(b)
+            }),
+        }));
+
+        // Sorted, if the comparator did not panic, but always structurally valid and holding the same entries
+        let mut sorted = model.entries();
+        sorted.sort();
+        let mut entries = map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>();
+        if result.is_err() {
+            entries.sort();
+        }
+        assert_eq!(entries, sorted);
+        assert_eq!(map.check_invariants(), Ok(()));
+        assert_eq!(map.resume().and_then(|c| c.key().copied()), model.saved);
+    }
+}
+
+#[test]
+fn test_sort_panic_on_second_comparison() {
+    let mut map: LinkedMap<u32, ()> = [3, 1, 2, 0].into_iter().map(|k| (k, ())).collect();
+    let mut calls = 0;
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        map.sort_by(|a, _, b, _| {
+            calls += 1;
+            assert!(calls < 2, "comparator panicked");
+            a.cmp(b)
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(map.check_invariants(), Ok(()));
+
+    // Only the first pair of nodes got merged
+    assert_eq!(
+        map.iter().map(|(k, _)| *k).collect::<Vec<_>>(),
+        [1, 3, 2, 0]
+    );
+}
+
+/// Assert the map is sorted by value and holds exactly the entries of the model
+pub(crate) fn compare_sorted(map: &LinkedMap<u32, u32>, values: &HashMap<u32, u32>) {
+    assert!(map.is_sorted_by(|_, a, _, b| a.cmp(b)));
+    let entries: HashMap<_, _> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(&entries, values);
+    assert_eq!(map.len(), values.len());
+    assert_eq!(map.check_invariants(), Ok(()));
+}
+
+gen_tests! {test_insert_sorted}
+fn test_insert_sorted(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = LinkedMap::new();
+    let mut values = HashMap::new();
+    let by_value = |_: &u32, a: &u32, _: &u32, b: &u32| a.cmp(b);
+
+    for _ in 0..steps(2000) {
+        // Few distinct values, so equal entries are frequent
+        let k = rng.below(32) as u32;
+        let v = rng.below(8) as u32;
+        let hint_key = rng.below(40) as u32;
+        match rng.below(7) {
+            0 => assert_eq!(
+                map.insert_sorted_by(k, v, ScanFrom::Head, by_value),
+                values.insert(k, v)
+            ),
+            1 => assert_eq!(
+                map.insert_sorted_by(k, v, ScanFrom::Tail, by_value),
+                values.insert(k, v)
+            ),
+            2 => assert_eq!(
+                map.insert_sorted_by(k, v, ScanFrom::Key(&hint_key), by_value),
+                values.insert(k, v)
+            ),
+            3 => {
+                let mut c = match values.contains_key(&hint_key) {
+                    true => map.cursor_at_mut(&hint_key).unwrap(),
+                    false => map.cursor_mut(),
+                };
+                assert_eq!(c.insert_sorted_by(k, v, by_value), values.insert(k, v));
+                assert_eq!(c.key(), Some(&k));
+            }
+            4 => assert_eq!(map.remove(&k), values.remove(&k)),
+            _ => {
+                // A panicking comparator must leave the map unchanged
+                let before: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+                let panic_at = rng.below(4);
+                let mut calls = 0;
+                let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    map.insert_sorted_by(k, v, ScanFrom::Key(&hint_key), |_, a, _, b| {
+                        calls += 1;
+                        assert!(calls <= panic_at, "comparator panicked");
+                        a.cmp(b)
+                    })
+                }));
+                match result {
+                    Ok(old) => assert_eq!(old, values.insert(k, v)),
+                    Err(_) => assert_eq!(
+                        map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(),
+                        before
+                    ),
+                }
+            }
+        }
+        compare_sorted(&map, &values);
+    }
+}
+
+/// Assert the labels order the map like the model, comparing every pair of entries
+fn compare_positions(map: &LinkedMap<u32, ()>, order: &[u32]) {
+    if let Err(e) = map.check_invariants() {
+        panic!("invariant violated: {}", e);
+    }
+    assert!(map.iter().map(|(k, _)| k).eq(order));
+    for pair in order.windows(2) {
+        assert_eq!(map.cmp_position(&pair[0], &pair[1]), Some(Ordering::Less));
+    }
+
+    // Look the nodes up once, since hashing would dominate the quadratic number of comparisons
+    let nodes: Vec<_> = order.iter().map(|k| map.map[k]).collect();
+    for (i, &a) in nodes.iter().enumerate() {
+        for (j, &b) in nodes.iter().enumerate() {
+            assert_eq!(unsafe { map.list.cmp_position(0, a, b) }, i.cmp(&j));
+        }
+    }
+}
+
+#[test]
+fn test_label_stress() {
+    let mut map = LinkedMap::new();
+    let mut order = Vec::new();
+    let mut next_key = 0..;
+
+    // Repeatedly exhaust the label range below the head
+    for k in next_key.by_ref().take(steps(5000)) {
+        map.prepend(k, ());
+        order.insert(0, k);
+    }
+    compare_positions(&map, &order);
+
+    // Repeatedly split the gap after the head
+    for k in next_key.by_ref().take(steps(5000)) {
+        map.cursor_mut().insert_after(k, ());
+        order.insert(1, k);
+    }
+    compare_positions(&map, &order);
+
+    // Alternate between inserting after and before the last inserted entry, so each insertion halves the same gap
+    let mut last = order[order.len() / 2];
+    for (i, k) in next_key.by_ref().take(steps(3000)).enumerate() {
+        let mut c = map.cursor_at_mut(&last).unwrap();
+        let at = order.iter().position(|o| *o == last).unwrap();
+        if i % 2 == 0 {
+            c.insert_after(k, ());
+            order.insert(at + 1, k);
+        } else {
+            c.insert_before(k, ());
+            order.insert(at, k);
+        }
+        last = k;
+    }
+    compare_positions(&map, &order);
+}
+
+/// Map with three orderings, modeled by a queue of keys per ordering
+type MultiOrderMap = LinkedMap<u32, u32, DefaultHashBuilder, 3>;
+
+/// Assert every ordering of the map matches the model orders of its keys
+fn compare_orders(map: &MultiOrderMap, orders: &[VecDeque<u32>; 3], values: &HashMap<u32, u32>) {
+    for (order, keys) in orders.iter().enumerate() {
+        let expected: Vec<_> = keys.iter().map(|k| (*k, values[k])).collect();
+        let forward: Vec<_> = map.iter_in(order).map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(forward, expected);
+        let mut backward: Vec<_> = map.iter_rev_in(order).map(|(k, v)| (*k, *v)).collect();
+        backward.reverse();
+        assert_eq!(backward, expected);
+    }
+    assert_eq!(map.len(), values.len());
+    assert_eq!(map.check_invariants(), Ok(()));
+}
+
+gen_tests! {test_multi_order_operations}
+fn test_multi_order_operations(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = MultiOrderMap::default();
+    let mut orders: [VecDeque<u32>; 3] = Default::default();
+    let mut values = HashMap::new();
+
+    for _ in 0..steps(2000) {
+        let k = rng.below(32) as u32;
+        let anchor = rng.below(32) as u32;
+        let v = rng.next() as u32;
+        let order = rng.below(3) as usize;
+        let keys = &mut orders[order];
+        let present = values.contains_key(&k);
+        match rng.below(10) {
+            0 | 1 => {
+                match present {
+                    true => orders[0].retain(|o| *o != k),
+                    false => orders[1..].iter_mut().for_each(|o| o.push_back(k)),
+                }
+                orders[0].push_back(k);
+                assert_eq!(map.append(k, v), values.insert(k, v));
+            }
+            2 => {
+                orders.iter_mut().for_each(|o| o.retain(|o| *o != k));
+                assert_eq!(map.remove(&k), values.remove(&k));
+            }
+            3 => {
+                assert_eq!(map.move_to_front_in(order, &k), present);
+                if present {
+                    keys.retain(|o| *o != k);
+                    keys.push_front(k);
+                }
+            }
+            4 => {
+                assert_eq!(map.move_to_back_in(order, &k), present);
+                if present {
+                    keys.retain(|o| *o != k);
+                    keys.push_back(k);
+                }
+            }
+            5 | 6 => {
+                let before = rng.below(2) == 0;
+                let moved = match before {
+                    true => map.move_before_in(order, &k, &anchor),
+                    false => map.move_after_in(order, &k, &anchor),
+                };
+                assert_eq!(moved, present && values.contains_key(&anchor));
+                if moved && k != anchor {
+                    keys.retain(|o| *o != k);
+                    let i = keys.iter().position(|o| *o == anchor).unwrap();
+                    keys.insert(if before { i } else { i + 1 }, k);
+                }
+            }
+            7 => {
+                assert_eq!(
+                    map.swap_in(order, &k, &anchor),
+                    present && values.contains_key(&anchor)
+                );
+                if let (Some(i), Some(j)) = (
+                    keys.iter().position(|o| *o == k),
+                    keys.iter().position(|o| *o == anchor),
+                ) {
+                    keys.swap(i, j);
+                }
+            }
+            8 => {
+                map.reverse_in(order);
+                keys.make_contiguous().reverse();
+            }
+            _ => {
+                let position = |key| keys.iter().position(|o| *o == key);
+                let expected = match (position(k), position(anchor)) {
+                    (Some(i), Some(j)) => Some(i.cmp(&j)),
+                    _ => None,
+                };
+                assert_eq!(map.cmp_position_in(order, &k, &anchor), expected);
+            }
+        }
+        compare_orders(&map, &orders, &values);
+    }
+}
+
+gen_tests! {test_order_cursor_operations}
+fn test_order_cursor_operations(seed: u64) {
+    let mut rng = Rng(seed);
+    let mut map = MultiOrderMap::default();
+    let mut orders: [VecDeque<u32>; 3] = Default::default();
+    let mut values = HashMap::new();
+    let mut current = None;
+
+    for _ in 0..steps(2000) {
+        let k = rng.below(32) as u32;
+        let v = rng.next() as u32;
+        let n = rng.below(4) as usize;
+        let order = rng.below(3) as usize;
+        let mut cursor = match current {
+            Some(current) => map.cursor_at_mut_in(order, &current).unwrap(),
+            None => map.cursor_mut_in(order),
+        };
+        let keys = &orders[order];
+        let pos = current.map(|c| keys.iter().position(|o| *o == c).unwrap());
+        let neighbour = |offset: isize| {
+            pos.and_then(|p| p.checked_add_signed(offset))
+                .and_then(|p| keys.get(p).copied())
+        };
+        let remove = |orders: &mut [VecDeque<u32>; 3], values: &mut HashMap<_, _>, k| {
+            orders.iter_mut().for_each(|o| o.retain(|o| *o != k));
+            (k, values.remove(&k).unwrap())
+        };
+
+        match rng.below(12) {
+            0 => {
+                let to = neighbour(1);
+                assert_eq!(cursor.next().map(|(k, _)| *k), to);
+                current = to.or(current);
+            }
+            1 => {
+                let to = neighbour(-1);
+                assert_eq!(cursor.previous().map(|(k, _)| *k), to);
+                current = to.or(current);
+            }
+            2 | 3 => {
+                let after = rng.below(2) == 0;
+                let outcome = match after {
+                    true => cursor.insert_after(k, v),
+                    false => cursor.insert_before(k, v),
+                };
+                let expected = match (current, values.insert(k, v)) {
+                    (None, _) => {
+                        orders.iter_mut().for_each(|o| o.push_back(k));
+                        current = Some(k);
+                        InsertOutcome::Inserted
+                    }
+                    (Some(c), Some(old_value)) if c == k => {
+                        InsertOutcome::UpdatedInPlace { old_value }
+                    }
+                    (Some(c), old_value) => {
+                        match old_value {
+                            Some(_) => orders[order].retain(|o| *o != k),
+                            None => (0..3)
+                                .filter(|o| *o != order)
+                                .for_each(|o| orders[o].push_back(k)),
+                        }
+                        let keys = &mut orders[order];
+                        let i = keys.iter().position(|o| *o == c).unwrap();
+                        keys.insert(i + after as usize, k);
+                        match old_value {
+                            Some(old_value) => InsertOutcome::Moved { old_value },
+                            None => InsertOutcome::Inserted,
+                        }
+                    }
+                };
+                assert_eq!(outcome, expected);
+            }
+            4 => {
+                let advance = rng.below(2) == 0;
+                let (removed, to) = match advance {
+                    true => (
+                        cursor.remove_and_advance(),
+                        neighbour(1).or_else(|| neighbour(-1)),
+                    ),
+                    false => (cursor.remove(), neighbour(-1).or_else(|| neighbour(1))),
+                };
+                let expected = current.map(|c| remove(&mut orders, &mut values, c));
+                assert_eq!(removed, expected);
+                current = to;
+            }
+            5 => {
+                let (removed, k) = match rng.below(2) {
+                    0 => (cursor.remove_next(), neighbour(1)),
+                    _ => (cursor.remove_previous(), neighbour(-1)),
+                };
+                assert_eq!(removed, k.map(|k| remove(&mut orders, &mut values, k)));
+            }
+            6 => {
+                let front = rng.below(2) == 0;
+                match front {
+                    true => cursor.move_to_front(),
+                    false => cursor.move_to_back(),
+                }
+                if let Some(c) = current {
+                    let keys = &mut orders[order];
+                    keys.retain(|o| *o != c);
+                    match front {
+                        true => keys.push_front(c),
+                        false => keys.push_back(c),
+                    }
+                }
+            }
+            7 => {
+                let before = rng.below(2) == 0;
+                let moved = match before {
+                    true => cursor.move_before_key(&k),
+                    false => cursor.move_after_key(&k),
+                };
+                assert_eq!(moved, current.is_some() && values.contains_key(&k));
+                match current {
+                    Some(c) if moved && c != k => {
+                        let keys = &mut orders[order];
+                        keys.retain(|o| *o != c);
+                        let i = keys.iter().position(|o| *o == k).unwrap();
+                        keys.insert(if before { i } else { i + 1 }, c);
+                    }
+                    _ => (),
+                }
+            }
+            8 => {
+                let (swapped, offset) = match rng.below(2) {
+                    0 => (cursor.swap_with_next(), 1),
+                    _ => (cursor.swap_with_previous(), -1),
+                };
+                let other = neighbour(offset);
+                assert_eq!(swapped, other.is_some());
+                if let (Some(p), true) = (pos, swapped) {
+                    orders[order].swap(p, p.checked_add_signed(offset).unwrap());
+                }
+            }
+            9 => {
+                let forward = rng.below(2) == 0;
+                let (sought, expected) = match forward {
+                    true => (
+                        cursor.seek_forward(|key, _| *key == k),
+                        keys.iter().skip(pos.unwrap_or(0)).position(|o| *o == k),
+                    ),
+                    false => (
+                        cursor.seek_backward(|key, _| *key == k),
+                        pos.and_then(|p| keys.iter().take(p + 1).rev().position(|o| *o == k)),
+                    ),
+                };
+                let end = match (pos, forward) {
+                    (None, _) => 0,
+                    (Some(p), true) => keys.len() - 1 - p,
+                    (Some(p), false) => p,
+                };
+                assert_eq!(sought, expected.ok_or(end));
+                let offset = sought.unwrap_or_else(|e| e) as isize;
+                current = neighbour(if forward { offset } else { -offset });
+            }
+            10 => {
+                let forward = rng.below(2) == 0;
+                let moved = match forward {
+                    true => cursor.advance_by(n),
+                    false => cursor.retreat_by(n),
+                };
+                let available = match (pos, forward) {
+                    (None, _) => 0,
+                    (Some(p), true) => keys.len() - 1 - p,
+                    (Some(p), false) => p,
+                };
+                assert_eq!(
+                    moved,
+                    if n <= available {
+                        Ok(n)
+                    } else {
+                        Err(available)
+                    }
+                );
+                let offset = moved.unwrap_or_else(|e| e) as isize;
+                current = neighbour(if forward { offset } else { -offset });
+            }
+            _ => {
+                assert_eq!(cursor.peek_nth(n).map(|(k, _)| *k), neighbour(n as isize));
+                let replaced = cursor.replace_value(v);
+                assert_eq!(replaced, current.and_then(|c| values.insert(c, v)));
+            }
+        }
+
+        let cursor = match current {
+            Some(current) => map.cursor_at_in(order, &current).unwrap(),
+            None => map.cursor_in(order),
+        };
+        assert_eq!(cursor.key().copied(), current);
+        compare_orders(&map, &orders, &values);
+    }
+}
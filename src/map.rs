@@ -2,7 +2,9 @@
 //! Credit for those goes to the appropriate code authors.
 
 use std::{
+    cmp::Ordering,
     hash::{BuildHasher, Hash},
+    iter::Rev,
     ptr::{null_mut, NonNull},
 };
 
@@ -12,19 +14,51 @@ pub use hashbrown::hash_map::DefaultHashBuilder;
 
 use crate::{
     linked_list::{list::Node, LinkedList},
-    Cursor, CursorMut,
+    Cursor, CursorMut, Drain, Range, RangeMut,
 };
 
+/// First and last node of a run of nodes. Both are null for an empty run.
+type RunBounds<K, V> = (*mut Node<K, V>, *mut Node<K, V>);
+
 /// Key-value store with linked-list reordering capabilities a cursor API and memory
-pub struct LinkedMap<K, V, S = DefaultHashBuilder> {
-    /// Stores node order
-    pub(crate) list: LinkedList<K, V>,
+///
+/// The entries are kept in `N` independent orderings, that all share one hash index and store every entry once.
+/// New entries are linked into every ordering and each ordering can then be rearranged without affecting the others
+/// through the `_in` methods and cursors created by [cursor_in](LinkedMap::cursor_in) and its siblings. Methods
+/// without the `_in` suffix work on ordering `0`. Sorting, rotating, ranges, splitting and splicing are only
+/// available for maps with a single ordering.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::{DefaultHashBuilder, LinkedMap};
+///
+/// const INSERTION: usize = 0;
+/// const ACCESS: usize = 1;
+/// const PRIORITY: usize = 2;
+///
+/// let mut map: LinkedMap<_, _, DefaultHashBuilder, 3> = LinkedMap::default();
+/// map.append("a", 1);
+/// map.append("b", 2);
+/// map.append("c", 3);
+///
+/// map.move_to_front_in(ACCESS, &"c");
+/// map.move_to_back_in(PRIORITY, &"a");
+///
+/// let keys = |order| map.iter_in(order).map(|(k, _)| *k).collect::<Vec<_>>();
+/// assert_eq!(keys(INSERTION), ["a", "b", "c"]);
+/// assert_eq!(keys(ACCESS), ["c", "a", "b"]);
+/// assert_eq!(keys(PRIORITY), ["b", "c", "a"]);
+/// ```
+pub struct LinkedMap<K, V, S = DefaultHashBuilder, const N: usize = 1> {
+    /// Stores the order of the nodes in every ordering
+    pub(crate) list: LinkedList<K, V, N>,
 
     /// Stores key-value relations for quick lookup
-    pub(crate) map: HashMap<K, NonNull<Node<K, V>>, S>,
+    pub(crate) map: HashMap<K, NonNull<Node<K, V, N>>, S>,
 
     /// A node stored by the user for reconstructing a cursor later on. Can be null.
-    pub(crate) saved: *mut Node<K, V>,
+    pub(crate) saved: *mut Node<K, V, N>,
 }
 
 impl<K, V> LinkedMap<K, V, DefaultHashBuilder> {
@@ -180,7 +214,9 @@ impl<K, V, S> LinkedMap<K, V, S> {
             saved: null_mut(),
         }
     }
+}
 
+impl<K, V, S, const N: usize> LinkedMap<K, V, S, N> {
     /// Returns the number of elements in the map.
     ///
     /// # Examples
@@ -236,7 +272,7 @@ impl<K, V, S> LinkedMap<K, V, S> {
 }
 
 // TODO: add examples to all of these
-impl<K, V, S> LinkedMap<K, V, S>
+impl<K, V, S, const N: usize> LinkedMap<K, V, S, N>
 where
     K: Eq + Hash + Clone + 'static,
     V: 'static,
@@ -244,10 +280,11 @@ where
 {
     /// Inserts a key-value pair at the start of the [LinkedMap].
     ///
-    /// If the map did not have this key present, [`None`] is returned.
+    /// If the map did not have this key present, [`None`] is returned and the new entry is linked to the start of
+    /// every ordering.
     ///
-    /// If the map did have this key present, the value is updated, and the old
-    /// value is returned. The key is not updated, though; this matters for
+    /// If the map did have this key present, the value is updated, the entry is moved to the start of ordering `0`
+    /// and the old value is returned. The key is not updated, though; this matters for
     /// types that can be `==` without being identical. See the [`std::collections`]
     /// [module-level documentation] for more.
     ///
@@ -261,35 +298,38 @@ where
     /// use linked_map::LinkedMap;
     ///
     /// let mut map = LinkedMap::new();
-    /// assert_eq!(map.insert(37, "a"), None);
+    /// assert_eq!(map.prepend(37, "a"), None);
     /// assert_eq!(map.is_empty(), false);
     ///
-    /// map.insert(37, "b");
-    /// assert_eq!(map.insert(37, "c"), Some("b"));
-    /// assert_eq!(map[&37], "c");
+    /// map.prepend(37, "b");
+    /// assert_eq!(map.prepend(37, "c"), Some("b"));
+    /// assert_eq!(map.iter().next(), Some((&37, &"c")));
     /// ```
     #[inline]
     pub fn prepend(&mut self, k: K, mut v: V) -> Option<V> {
-        match self.map.entry(k.clone()) {
-            Entry::Occupied(mut e) => {
-                let node = e.get_mut();
+        let old = match self.map.entry(k.clone()) {
+            Entry::Occupied(e) => {
+                let mut node = *e.get();
                 std::mem::swap(&mut unsafe { node.as_mut() }.val, &mut v);
-                unsafe { CursorMut::new(self, node.as_ptr()) }.move_to_front(NavigateTo::MovedNode);
+                self.list.move_to_front(0, node);
                 Some(v)
             }
             Entry::Vacant(e) => {
                 e.insert(self.list.prepend(k, v));
                 None
             }
-        }
+        };
+        self.debug_check_invariants();
+        old
     }
 
     /// Inserts a key-value pair at the end of the [LinkedMap].
     ///
-    /// If the map did not have this key present, [`None`] is returned.
+    /// If the map did not have this key present, [`None`] is returned and the new entry is linked to the end of every
+    /// ordering.
     ///
-    /// If the map did have this key present, the value is updated, and the old
-    /// value is returned. The key is not updated, though; this matters for
+    /// If the map did have this key present, the value is updated, the entry is moved to the end of ordering `0` and
+    /// the old value is returned. The key is not updated, though; this matters for
     /// types that can be `==` without being identical. See the [`std::collections`]
     /// [module-level documentation] for more.
     ///
@@ -311,124 +351,1675 @@ where
     /// assert_eq!(map[&37], "c");
     /// ```
     #[inline]
-    pub fn append(&mut self, k: K, v: V) -> Option<V> {
-        todo!()
+    pub fn append(&mut self, k: K, mut v: V) -> Option<V> {
+        let old = match self.map.entry(k.clone()) {
+            Entry::Occupied(e) => {
+                let mut node = *e.get();
+                std::mem::swap(&mut unsafe { node.as_mut() }.val, &mut v);
+                self.list.move_to_back(0, node);
+                Some(v)
+            }
+            Entry::Vacant(e) => {
+                e.insert(self.list.append(k, v));
+                None
+            }
+        };
+        self.debug_check_invariants();
+        old
     }
-}
 
-// TODO: add examples to all of these
-impl<K, V, S> LinkedMap<K, V, S>
-where
-    K: Eq + Hash + Clone + 'static,
-    V: 'static,
-    S: BuildHasher,
-{
-    /// Construct and navigate cursor to a saved node position, saved via [CursorMut::save](CursorMut::save).
+    /// Returns a reference to the value corresponding to the key.
     ///
-    /// If no node is currently saved, returns [None].
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map = LinkedMap::new();
+    /// map.append(1, "a");
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
     #[inline]
-    pub fn resume(&self) -> Option<Cursor<'_, K, V, S>> {
-        unsafe { self.saved.as_mut().map(|saved| Cursor::new(self, saved)) }
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.map.get(k).map(|n| &unsafe { n.as_ref() }.val)
     }
 
-    /// Construct and navigate a mutable cursor to a saved node position, saved via [CursorMut::save](CursorMut::save).
+    /// Returns a mutable reference to the value corresponding to the key.
     ///
-    /// If no node is currently saved, returns [None].
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map = LinkedMap::new();
+    /// map.append(1, "a");
+    /// if let Some(x) = map.get_mut(&1) {
+    ///     *x = "b";
+    /// }
+    /// assert_eq!(map.get(&1), Some(&"b"));
+    /// ```
     #[inline]
-    pub fn resume_mut(&mut self) -> Option<CursorMut<'_, K, V, S>> {
-        unsafe { self.saved.as_mut().map(|saved| CursorMut::new(self, saved)) }
+    pub fn get_mut(&mut self, k: &K) -> Option<&mut V> {
+        self.map
+            .get(k)
+            .copied()
+            .map(|mut n| &mut unsafe { n.as_mut() }.val)
     }
 
-    /// Clear any saved node. See [CursorMut::save()](CursorMut::save) for details.
+    /// Returns `true` if the map contains a value for the specified key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map = LinkedMap::new();
+    /// map.append(1, "a");
+    /// assert_eq!(map.contains_key(&1), true);
+    /// assert_eq!(map.contains_key(&2), false);
+    /// ```
     #[inline]
-    pub fn clear_saved(&mut self) {
-        self.saved = null_mut();
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.map.contains_key(k)
     }
 
-    /// Iterate the list from head to tail
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
-        unsafe { Cursor::new(self, self.list.head()) }.iter()
+    /// Removes a key from the map, returning the value at the key if the key was previously in the map.
+    ///
+    /// If the removed node was saved, the saved node is cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map = LinkedMap::new();
+    /// map.append(1, "a");
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.map.remove(k).map(|node| {
+            self.list.remove(node);
+            if self.saved == node.as_ptr() {
+                self.saved = null_mut();
+            }
+            self.debug_check_invariants();
+            unsafe { Box::from_raw(node.as_ptr()) }.val
+        })
     }
 
-    /// Iterate the list from tail to head
+    /// Validate the internal structure of the map in O(n).
+    ///
+    /// Checks that the list links of every ordering are symmetric and consistent with the head and tail, that all
+    /// orderings hold the same nodes, that the list and the hash index contain the same number of entries, that every
+    /// index entry points at a node with an equal key and that the saved node is in the list.
+    ///
+    /// With the `debug-invariants` feature enabled, this runs after every mutation and panics on violations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// map.move_after(&1, &3);
+    /// assert_eq!(map.check_invariants(), Ok(()));
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let positions = self.list.check_links()?;
+        if positions.len() != self.map.len() {
+            return Err(InvariantViolation::LengthMismatch {
+                list: positions.len(),
+                map: self.map.len(),
+            });
+        }
+        for (k, node) in self.map.iter() {
+            match positions.get(&node.as_ptr()) {
+                Some(&index) if unsafe { &node.as_ref().key } != k => {
+                    return Err(InvariantViolation::KeyMismatch { index })
+                }
+                Some(_) => (),
+                None => return Err(InvariantViolation::UnlinkedIndexEntry),
+            }
+        }
+        if !self.saved.is_null() && !positions.contains_key(&self.saved) {
+            return Err(InvariantViolation::SavedUnreachable);
+        }
+        Ok(())
+    }
+
+    /// Panic, if the map is structurally invalid. Only checks with the `debug-invariants` feature enabled.
     #[inline]
-    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
-        unsafe { Cursor::new(self, self.list.tail()) }.iter_rev()
+    pub(crate) fn debug_check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(e) = self.check_invariants() {
+            panic!("LinkedMap invariant violated: {e}");
+        }
     }
 
-    /// Iterate the list mutably from head to tail
+    /// Compare the positions of the entries of `a` and `b` in the list.
+    ///
+    /// Each node carries an order-maintenance label, that is kept up to date by every operation reordering entries,
+    /// so this is O(1).
+    ///
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// assert_eq!(map.cmp_position(&1, &3), Some(Ordering::Less));
+    ///
+    /// map.move_before(&3, &1);
+    /// assert_eq!(map.cmp_position(&1, &3), Some(Ordering::Greater));
+    /// assert_eq!(map.cmp_position(&1, &4), None);
+    /// ```
     #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
-        unsafe { CursorMut::new(self, self.list.head()) }.iter()
+    pub fn cmp_position(&self, a: &K, b: &K) -> Option<Ordering> {
+        self.cmp_position_in(0, a, b)
     }
 
-    /// Iterate the list mutably from tail to head
+    /// Compare the positions of the entries of `a` and `b` in the ordering `order` in O(1).
+    ///
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    /// use std::cmp::Ordering;
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// map.move_to_back_in(1, &1);
+    /// assert_eq!(map.cmp_position_in(0, &1, &3), Some(Ordering::Less));
+    /// assert_eq!(map.cmp_position_in(1, &1, &3), Some(Ordering::Greater));
+    /// ```
     #[inline]
-    pub fn iter_rev_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
-        unsafe { CursorMut::new(self, self.list.tail()) }.iter_rev()
+    pub fn cmp_position_in(&self, order: usize, a: &K, b: &K) -> Option<Ordering> {
+        Self::assert_order(order);
+        match (self.map.get(a), self.map.get(b)) {
+            (Some(&a), Some(&b)) => Some(unsafe { self.list.cmp_position(order, a, b) }),
+            _ => None,
+        }
     }
 
-    /// Create a cursor over the linked map.
+    /// Move the entry of `k` to the start of ordering `0` in O(1).
     ///
-    /// The Cursor is set to the start of the list.
+    /// Returns `false`, if the key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// assert!(map.move_to_front(&3));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 1, 2]);
+    /// assert!(!map.move_to_front(&4));
+    /// ```
     #[inline]
-    pub fn cursor(&self) -> Cursor<'_, K, V, S> {
-        unsafe { Cursor::new(self, self.list.head()) }
+    pub fn move_to_front(&mut self, k: &K) -> bool {
+        self.move_to_front_in(0, k)
     }
 
-    /// Create a mutable cursor over the linked map.
+    /// Move the entry of `k` to the start of the ordering `order` in O(1), leaving the other orderings untouched.
     ///
-    /// The Cursor is set to the start of the list.
+    /// Returns `false`, if the key is not in the map.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// assert!(map.move_to_front_in(1, &3));
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [3, 1, 2]);
+    /// ```
+    pub fn move_to_front_in(&mut self, order: usize, k: &K) -> bool {
+        Self::assert_order(order);
+        match self.map.get(k) {
+            Some(&node) => {
+                self.list.move_to_front(order, node);
+                self.debug_check_invariants();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move the entry of `k` to the end of ordering `0` in O(1).
+    ///
+    /// Returns `false`, if the key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=3).map(|i| (i, ())).collect();
+    /// assert!(map.move_to_back(&1));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// assert!(!map.move_to_back(&4));
+    /// ```
     #[inline]
-    pub fn cursor_mut(&mut self) -> CursorMut<'_, K, V, S> {
-        unsafe { CursorMut::new(self, self.list.head()) }
+    pub fn move_to_back(&mut self, k: &K) -> bool {
+        self.move_to_back_in(0, k)
     }
 
-    /// Create a cursor navigated to the passed key.
+    /// Move the entry of `k` to the end of the ordering `order` in O(1), leaving the other orderings untouched.
     ///
-    /// This is a shorthand for constricting a cursor and calling `to_key()`.
+    /// Returns `false`, if the key is not in the map.
     ///
-    /// Returns [None], if the key is not in the map,
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// assert!(map.move_to_back_in(1, &1));
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// ```
+    pub fn move_to_back_in(&mut self, order: usize, k: &K) -> bool {
+        Self::assert_order(order);
+        match self.map.get(k) {
+            Some(&node) => {
+                self.list.move_to_back(order, node);
+                self.debug_check_invariants();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move the entry of `k` right before the entry of `anchor` in O(1).
+    ///
+    /// Returns `false`, if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.move_before(&4, &2));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 4, 2, 3]);
+    /// assert!(!map.move_before(&5, &2));
+    /// ```
     #[inline]
-    pub fn cursor_at(&self, k: &K) -> Option<Cursor<K, V, S>> {
-        self.map
-            .get(k)
-            .map(|n| unsafe { Cursor::new(self, n.as_ptr()) })
+    pub fn move_before(&mut self, k: &K, anchor: &K) -> bool {
+        self.move_before_in(0, k, anchor)
     }
 
-    /// Create a mutable cursor navigated to the passed key.
+    /// Move the entry of `k` right before the entry of `anchor` in the ordering `order` in O(1), leaving the other
+    /// orderings untouched.
     ///
-    /// This is a shorthand for constricting a cursor and calling `to_key()`.
+    /// Returns `false`, if either key is not in the map.
     ///
-    /// Returns [None], if the key is not in the map,
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.move_before_in(1, &4, &2));
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 4, 2, 3]);
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    pub fn move_before_in(&mut self, order: usize, k: &K, anchor: &K) -> bool {
+        Self::assert_order(order);
+        match (self.map.get(k), self.map.get(anchor)) {
+            (Some(&node), Some(&anchor)) => {
+                unsafe { self.list.move_before(order, node, anchor) };
+                self.debug_check_invariants();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Move the entry of `k` right after the entry of `anchor` in O(1).
+    ///
+    /// Returns `false`, if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.move_after(&1, &3));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1, 4]);
+    /// assert!(!map.move_after(&1, &5));
+    /// ```
     #[inline]
-    pub fn cursor_at_mut(&mut self, k: &K) -> Option<CursorMut<K, V, S>> {
-        self.map
-            .get(k)
-            .copied()
-            .map(|n| unsafe { CursorMut::new(self, n.as_ptr()) })
+    pub fn move_after(&mut self, k: &K, anchor: &K) -> bool {
+        self.move_after_in(0, k, anchor)
     }
-}
 
-impl<K, V, S> Default for LinkedMap<K, V, S>
-where
-    S: Default,
-{
+    /// Move the entry of `k` right after the entry of `anchor` in the ordering `order` in O(1), leaving the other
+    /// orderings untouched.
+    ///
+    /// Returns `false`, if either key is not in the map.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.move_after_in(1, &1, &3));
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1, 4]);
+    /// ```
+    pub fn move_after_in(&mut self, order: usize, k: &K, anchor: &K) -> bool {
+        Self::assert_order(order);
+        match (self.map.get(k), self.map.get(anchor)) {
+            (Some(&node), Some(&anchor)) => {
+                unsafe { self.list.move_after(order, node, anchor) };
+                self.debug_check_invariants();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Replace the key of the entry of `old` with `new`, keeping the entry's value and position.
+    ///
+    /// Fails without modifying the map, if `old` is not in the map, or if `new` is already in the map under a
+    /// different entry. The error hands back `new`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, RekeyError};
+    ///
+    /// let mut map: LinkedMap<_, _> = [("a.txt", 1), ("b.txt", 2)].into_iter().collect();
+    /// assert_eq!(map.rekey(&"a.txt", "c.txt"), Ok(()));
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [(&"c.txt", &1), (&"b.txt", &2)]);
+    /// assert_eq!(map.rekey(&"c.txt", "b.txt"), Err(RekeyError::Occupied("b.txt")));
+    /// assert_eq!(map.rekey(&"a.txt", "d.txt"), Err(RekeyError::NotFound("d.txt")));
+    /// assert_eq!(
+    ///     map.rekey(&"c.txt", "b.txt").unwrap_err().to_string(),
+    ///     r#"new key "b.txt" already present in map"#,
+    /// );
+    /// ```
+    pub fn rekey(&mut self, old: &K, new: K) -> Result<(), RekeyError<K>> {
+        match self.cursor_at_mut(old) {
+            Some(mut c) => c.replace_key(new).map(drop),
+            None => Err(RekeyError::NotFound(new)),
+        }
+    }
+
+    /// Swap the positions of the entries of `a` and `b` in O(1), without moving their keys or values.
+    ///
+    /// Returns `false`, if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.swap(&1, &3));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 2, 1, 4]);
+    /// assert!(map.swap(&1, &4));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 2, 4, 1]);
+    /// assert!(!map.swap(&1, &5));
+    /// ```
     #[inline]
-    fn default() -> Self {
-        Self {
-            list: LinkedList::new(),
-            map: Default::default(),
-            saved: null_mut(),
+    pub fn swap(&mut self, a: &K, b: &K) -> bool {
+        self.swap_in(0, a, b)
+    }
+
+    /// Swap the positions of the entries of `a` and `b` in the ordering `order` in O(1), leaving the other
+    /// orderings untouched.
+    ///
+    /// Returns `false`, if either key is not in the map.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=4).map(|i| (i, ())).collect();
+    /// assert!(map.swap_in(1, &1, &3));
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [3, 2, 1, 4]);
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// ```
+    pub fn swap_in(&mut self, order: usize, a: &K, b: &K) -> bool {
+        Self::assert_order(order);
+        match (self.map.get(a), self.map.get(b)) {
+            (Some(&a), Some(&b)) => {
+                unsafe { self.list.swap(order, a, b) };
+                self.debug_check_invariants();
+                true
+            }
+            _ => false,
         }
     }
-}
 
-// TODO: port as many methods and trait impls of the stdlib linked_list and hashbrown::HashMap as possible
-// TODO: sort_by and sort_by_stable
+    /// Reverse the order of the entries in O(n) without reallocating any nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=4).map(|i| (i, ())).collect();
+    /// map.reverse();
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn reverse(&mut self) {
+        self.reverse_in(0);
+    }
 
-impl<K, V, S> Clone for LinkedMap<K, V, S>
+    /// Reverse the ordering `order` in O(n), leaving the other orderings untouched.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// map.reverse_in(1);
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [3, 2, 1]);
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    pub fn reverse_in(&mut self, order: usize) {
+        Self::assert_order(order);
+        self.list.reverse(order);
+        self.debug_check_invariants();
+    }
+
+    /// Panic, if `order` is not the index of an ordering of the map
+    #[inline]
+    fn assert_order(order: usize) {
+        assert!(order < N, "ordering out of bounds");
+    }
+}
+
+impl<K, V, S> LinkedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Move all entries of `other` to the end of the [LinkedMap], leaving `other` empty.
+    ///
+    /// Relinking the nodes is O(1) and relabeling them for [cmp_position](LinkedMap::cmp_position) amortized O(m) for
+    /// `m` entries in `other`, so the cost is dominated by moving the keys between the hash indexes.
+    ///
+    /// If a key is present in both maps, the value of the existing entry is updated and the entry is moved to its
+    /// position among the appended entries, same as with [append](LinkedMap::append).
+    ///
+    /// If `other` has a saved node and this map does not, the saved node is carried over, so a
+    /// [split_off](LinkedMap::split_off) followed by appending the maps back together keeps it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut a: LinkedMap<_, _> = [(1, "a"), (2, "b")].into_iter().collect();
+    /// let mut b: LinkedMap<_, _> = [(3, "c"), (1, "A")].into_iter().collect();
+    ///
+    /// a.append_map(&mut b);
+    /// assert!(b.is_empty());
+    /// assert_eq!(a.iter().collect::<Vec<_>>(), [(&2, &"b"), (&3, &"c"), (&1, &"A")]);
+    /// ```
+    pub fn append_map(&mut self, other: &mut Self) {
+        unsafe { self.splice_nodes(self.list.tail(0), other, false) };
+    }
+
+    /// Move all entries of `other` to the start of the [LinkedMap], leaving `other` empty.
+    ///
+    /// Relinking the nodes is O(1) and relabeling them for [cmp_position](LinkedMap::cmp_position) amortized O(m) for
+    /// `m` entries in `other`, so the cost is dominated by moving the keys between the hash indexes.
+    ///
+    /// If a key is present in both maps, the value of the existing entry is updated and the entry is moved to its
+    /// position among the prepended entries, same as with [prepend](LinkedMap::prepend).
+    ///
+    /// If `other` has a saved node and this map does not, the saved node is carried over, so a
+    /// [split_off](LinkedMap::split_off) followed by appending the maps back together keeps it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut a: LinkedMap<_, _> = [(1, "a"), (2, "b")].into_iter().collect();
+    /// let mut b: LinkedMap<_, _> = [(3, "c"), (2, "B")].into_iter().collect();
+    ///
+    /// a.prepend_map(&mut b);
+    /// assert!(b.is_empty());
+    /// assert_eq!(a.iter().collect::<Vec<_>>(), [(&3, &"c"), (&2, &"B"), (&1, &"a")]);
+    /// ```
+    pub fn prepend_map(&mut self, other: &mut Self) {
+        unsafe { self.splice_nodes(null_mut(), other, false) };
+    }
+
+    /// Rotate the entries `n` places to the left, so that the entry at index `n` becomes the first one.
+    ///
+    /// Finding the new head and relabeling the entries moved to the other end take O(min(n, len - n)).
+    ///
+    /// # Panics
+    ///
+    /// If `n` is greater than `len()`. Note that `n == len()` does _not_ panic and is a no-op rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// map.rotate_left(2);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(n <= self.len(), "rotation out of bounds");
+        if let Some(head) = NonNull::new(self.node_at(n)) {
+            unsafe { self.list.make_head(0, head) };
+        }
+        self.debug_check_invariants();
+    }
+
+    /// Rotate the entries `n` places to the right, so that the entry at index `len - n` becomes the first one.
+    ///
+    /// Finding the new head and relabeling the entries moved to the other end take O(min(n, len - n)).
+    ///
+    /// # Panics
+    ///
+    /// If `n` is greater than `len()`. Note that `n == len()` does _not_ panic and is a no-op rotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// map.rotate_right(2);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [4, 5, 1, 2, 3]);
+    /// ```
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(n <= self.len(), "rotation out of bounds");
+        self.rotate_left(self.len() - n);
+    }
+
+    /// Stably sort the entries in place using the comparison function `cmp`.
+    ///
+    /// The comparison function receives two key-value pairs to compare.
+    ///
+    /// Runs a bottom-up merge sort over the list links in O(n log n) time and O(1) extra memory, without
+    /// reallocating any nodes or modifying the hash index. Saved nodes remain valid. If `cmp` panics, the map keeps
+    /// all its entries, but can be left partially sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, 'c'), (2, 'a'), (3, 'b'), (4, 'a')].into_iter().collect();
+    /// map.sort_by(|_, v1, _, v2| v1.cmp(v2));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 4, 3, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.list
+            .sort_by(0, |a, b| cmp(&a.key, &a.val, &b.key, &b.val));
+        self.debug_check_invariants();
+    }
+
+    /// Stably sort the entries in place using a sort key extraction function.
+    ///
+    /// See [sort_by](LinkedMap::sort_by) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, -3i32), (2, 1), (3, -2)].into_iter().collect();
+    /// map.sort_by_key(|_, v| v.abs());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// ```
+    pub fn sort_by_key<T, F>(&mut self, mut sort_key: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.list.sort_by(0, |a, b| {
+            sort_key(&a.key, &a.val).cmp(&sort_key(&b.key, &b.val))
+        });
+        self.debug_check_invariants();
+    }
+
+    /// Stably sort the entries in place using a sort key extraction function, calling it only once per entry.
+    ///
+    /// Useful for expensive key extraction functions. The keys are cached in a temporary buffer of O(n) size, the
+    /// nodes are sorted the same way as by [sort_by](LinkedMap::sort_by).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [("a", 30), ("b", 4), ("c", 100)].into_iter().collect();
+    /// map.sort_by_cached_key(|_, v| v.to_string());
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["c", "a", "b"]);
+    /// ```
+    pub fn sort_by_cached_key<T, F>(&mut self, mut sort_key: F)
+    where
+        T: Ord,
+        F: FnMut(&K, &V) -> T,
+    {
+        self.list
+            .sort_by_cached_key(0, |node| sort_key(&node.key, &node.val));
+        self.debug_check_invariants();
+    }
+
+    /// Sort the entries in place using the comparison function `cmp`, but might not preserve the order of equal
+    /// entries.
+    ///
+    /// A merge sort of linked nodes is not slower, when it is stable, so this currently runs the same sort as
+    /// [sort_by](LinkedMap::sort_by). It is provided for parity with the sorting methods of slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(3, ()), (1, ()), (2, ())].into_iter().collect();
+    /// map.sort_unstable_by(|k1, _, k2, _| k1.cmp(k2));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn sort_unstable_by<F>(&mut self, cmp: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        self.sort_by(cmp);
+    }
+
+    /// Insert a key-value pair at its sorted position according to `cmp`, assuming the entries are already sorted
+    /// by `cmp`.
+    ///
+    /// The new entry is placed after all entries comparing less than or equal to it. The position is found by
+    /// scanning from the node selected by `hint` in the needed direction, so a good hint makes insertion O(1).
+    ///
+    /// If the map did not have this key present, [`None`] is returned.
+    ///
+    /// If the map did have this key present, the value is updated, the entry is moved to its new sorted position
+    /// and the old value is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, ScanFrom};
+    ///
+    /// let mut map: LinkedMap<&str, u32> = LinkedMap::new();
+    /// let by_deadline = |_: &&str, a: &u32, _: &&str, b: &u32| a.cmp(b);
+    ///
+    /// map.insert_sorted_by("c", 30, ScanFrom::Tail, by_deadline);
+    /// map.insert_sorted_by("a", 10, ScanFrom::Head, by_deadline);
+    /// map.insert_sorted_by("b", 20, ScanFrom::Key(&"a"), by_deadline);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["a", "b", "c"]);
+    ///
+    /// assert_eq!(map.insert_sorted_by("a", 40, ScanFrom::Tail, by_deadline), Some(10));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["b", "c", "a"]);
+    /// assert!(map.is_sorted_by(by_deadline));
+    /// ```
+    pub fn insert_sorted_by<F>(&mut self, k: K, v: V, hint: ScanFrom<'_, K>, cmp: F) -> Option<V>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let start = match hint {
+            ScanFrom::Head => self.list.head(0),
+            ScanFrom::Tail => self.list.tail(0),
+            ScanFrom::Key(k) => match self.map.get(k) {
+                Some(node) => node.as_ptr(),
+                None => self.list.tail(0),
+            },
+        };
+        unsafe { self.insert_sorted_from(k, v, start, cmp) }.1
+    }
+
+    /// Insert a key-value pair at its sorted position according to `cmp` by scanning from `start`.
+    /// If the key is already present, its node is moved to the new sorted position.
+    ///
+    /// Returns the inserted node and the old value, if any.
+    ///
+    /// # SAFETY
+    ///
+    /// `start` must be null or belong to this map.
+    pub(crate) unsafe fn insert_sorted_from<F>(
+        &mut self,
+        k: K,
+        v: V,
+        mut start: *mut Node<K, V>,
+        mut cmp: F,
+    ) -> (NonNull<Node<K, V>>, Option<V>)
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        // Find the position before modifying anything, so a panicking `cmp` leaves the map intact
+        let entry = self.map.entry(k.clone());
+        let skip = match &entry {
+            Entry::Occupied(e) => e.get().as_ptr(),
+            Entry::Vacant(_) => null_mut(),
+        };
+        if !skip.is_null() && start == skip {
+            start = match Node::previous_of(skip, 0) {
+                previous if !previous.is_null() => previous,
+                _ => Node::next_of(skip, 0),
+            };
+        }
+        let after = Self::sorted_anchor(start, skip, &k, &v, &mut cmp);
+
+        let (node, old) = match entry {
+            Entry::Occupied(e) => {
+                let node = *e.get();
+                let old = std::mem::replace(&mut (*node.as_ptr()).val, v);
+                match NonNull::new(after) {
+                    Some(after) => self.list.move_after(0, node, after),
+                    None => self.list.move_to_front(0, node),
+                }
+                (node, Some(old))
+            }
+            Entry::Vacant(e) => {
+                let node = *e.insert(Node::new(k, v));
+                match NonNull::new(after) {
+                    Some(after) => self.list.insert_after(0, node, after),
+                    None => self.list.prepend_node(node),
+                }
+                (node, None)
+            }
+        };
+
+        self.debug_check_invariants();
+        (node, old)
+    }
+
+    /// Find the node, after which an entry of `k` and `v` belongs according to `cmp`, by scanning from `start`.
+    /// The `skip` node is ignored during the scan.
+    ///
+    /// Returns null, if the entry belongs at the front of the list.
+    ///
+    /// # SAFETY
+    ///
+    /// `start` and `skip` must be null or belong to the same list. `start` must not be `skip`.
+    unsafe fn sorted_anchor<F>(
+        start: *mut Node<K, V>,
+        skip: *mut Node<K, V>,
+        k: &K,
+        v: &V,
+        cmp: &mut F,
+    ) -> *mut Node<K, V>
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let previous = |node: *mut Node<K, V>| match Node::previous_of(node, 0) {
+            previous if previous == skip => Node::previous_of(previous, 0),
+            previous => previous,
+        };
+        let next = |node: *mut Node<K, V>| match Node::next_of(node, 0) {
+            next if next == skip => Node::next_of(next, 0),
+            next => next,
+        };
+        let mut less =
+            |other: *mut Node<K, V>| cmp(k, v, &(*other).key, &(*other).val) == Ordering::Less;
+
+        if start.is_null() {
+            null_mut()
+        } else if less(start) {
+            let mut after = previous(start);
+            while !after.is_null() && less(after) {
+                after = previous(after);
+            }
+            after
+        } else {
+            let mut after = start;
+            while !next(after).is_null() && !less(next(after)) {
+                after = next(after);
+            }
+            after
+        }
+    }
+
+    /// Returns `true`, if the entries are sorted according to `cmp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = [(1, ()), (2, ()), (3, ())].into_iter().collect();
+    /// assert!(map.is_sorted_by(|k1, _, k2, _| k1.cmp(k2)));
+    /// assert!(!map.is_sorted_by(|k1, _, k2, _| k2.cmp(k1)));
+    /// ```
+    pub fn is_sorted_by<F>(&self, mut cmp: F) -> bool
+    where
+        F: FnMut(&K, &V, &K, &V) -> Ordering,
+    {
+        let mut node = self.list.head(0);
+        while let Some(current) = unsafe { node.as_ref() } {
+            node = current.next(0);
+            if let Some(next) = unsafe { node.as_ref() } {
+                if cmp(&current.key, &current.val, &next.key, &next.val) == Ordering::Greater {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Return a pointer to the node at position `i` in the list, walking from the closer end.
+    /// Returns null, if `i` is out of bounds.
+    pub(crate) fn node_at(&self, i: usize) -> *mut Node<K, V> {
+        let len = self.len();
+        if i >= len {
+            return null_mut();
+        }
+
+        unsafe {
+            if i <= len / 2 {
+                let mut node = self.list.head(0);
+                for _ in 0..i {
+                    node = (*node).next(0);
+                }
+                node
+            } else {
+                let mut node = self.list.tail(0);
+                for _ in i + 1..len {
+                    node = (*node).previous(0);
+                }
+                node
+            }
+        }
+    }
+}
+
+// TODO: add examples to all of these
+impl<K, V, S, const N: usize> LinkedMap<K, V, S, N>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Construct and navigate cursor to a saved node position, saved via [CursorMut::save](CursorMut::save).
+    ///
+    /// If no node is currently saved, returns [None].
+    #[inline]
+    pub fn resume(&self) -> Option<Cursor<'_, K, V, S, N>> {
+        NonNull::new(self.saved).map(|saved| unsafe { Cursor::new(self, saved.as_ptr()) })
+    }
+
+    /// Construct and navigate a mutable cursor to a saved node position, saved via [CursorMut::save](CursorMut::save).
+    ///
+    /// If no node is currently saved, returns [None].
+    #[inline]
+    pub fn resume_mut(&mut self) -> Option<CursorMut<'_, K, V, S, N>> {
+        NonNull::new(self.saved).map(|saved| unsafe { CursorMut::new(self, saved.as_ptr()) })
+    }
+
+    /// Clear any saved node. See [CursorMut::save()](CursorMut::save) for details.
+    #[inline]
+    pub fn clear_saved(&mut self) {
+        self.saved = null_mut();
+    }
+
+    /// Iterate the list from head to tail
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter_in(0)
+    }
+
+    /// Iterate the list from tail to head
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter_rev_in(0)
+    }
+
+    /// Iterate the list mutably from head to tail
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        unsafe { CursorMut::new(self, self.list.head(0)) }.iter()
+    }
+
+    /// Iterate the list mutably from tail to head
+    #[inline]
+    pub fn iter_rev_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        unsafe { CursorMut::new(self, self.list.tail(0)) }.iter_rev()
+    }
+
+    /// Iterate the ordering `order` from head to tail
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    #[inline]
+    pub fn iter_in(&self, order: usize) -> impl Iterator<Item = (&K, &V)> {
+        self.cursor_in(order).iter()
+    }
+
+    /// Iterate the ordering `order` from tail to head
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    #[inline]
+    pub fn iter_rev_in(&self, order: usize) -> impl Iterator<Item = (&K, &V)> {
+        let mut cursor = self.cursor_in(order);
+        cursor.to_back();
+        cursor.iter_rev()
+    }
+
+    /// Create a cursor over the linked map.
+    ///
+    /// The Cursor is set to the start of the list.
+    #[inline]
+    pub fn cursor(&self) -> Cursor<'_, K, V, S, N> {
+        self.cursor_in(0)
+    }
+
+    /// Create a mutable cursor over the linked map.
+    ///
+    /// The Cursor is set to the start of the list.
+    #[inline]
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, K, V, S, N> {
+        self.cursor_mut_in(0)
+    }
+
+    /// Create a cursor navigated to the passed key.
+    ///
+    /// This is a shorthand for constricting a cursor and calling `to_key()`.
+    ///
+    /// Returns [None], if the key is not in the map,
+    #[inline]
+    pub fn cursor_at(&self, k: &K) -> Option<Cursor<'_, K, V, S, N>> {
+        self.cursor_at_in(0, k)
+    }
+
+    /// Create a mutable cursor navigated to the passed key.
+    ///
+    /// This is a shorthand for constricting a cursor and calling `to_key()`.
+    ///
+    /// Returns [None], if the key is not in the map,
+    #[inline]
+    pub fn cursor_at_mut(&mut self, k: &K) -> Option<CursorMut<'_, K, V, S, N>> {
+        self.cursor_at_mut_in(0, k)
+    }
+
+    /// Create a cursor over the ordering `order` of the linked map.
+    ///
+    /// The Cursor is set to the start of the ordering.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// map.move_to_front_in(1, &2);
+    /// let mut cursor = map.cursor_in(1);
+    /// assert_eq!(cursor.key(), Some(&2));
+    /// assert_eq!(cursor.next(), Some((&1, &())));
+    /// ```
+    #[inline]
+    pub fn cursor_in(&self, order: usize) -> Cursor<'_, K, V, S, N> {
+        Self::assert_order(order);
+        unsafe { Cursor::new_in(self, order, self.list.head(order)) }
+    }
+
+    /// Create a mutable cursor over the ordering `order` of the linked map.
+    ///
+    /// The Cursor is set to the start of the ordering. Rearranging entries through the cursor only affects `order`.
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_mut_in(1);
+    /// cursor.move_to_back();
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3, 1]);
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn cursor_mut_in(&mut self, order: usize) -> CursorMut<'_, K, V, S, N> {
+        Self::assert_order(order);
+        let head = self.list.head(order);
+        unsafe { CursorMut::new_in(self, order, head) }
+    }
+
+    /// Create a cursor over the ordering `order` navigated to the passed key.
+    ///
+    /// Returns [None], if the key is not in the map,
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    #[inline]
+    pub fn cursor_at_in(&self, order: usize, k: &K) -> Option<Cursor<'_, K, V, S, N>> {
+        Self::assert_order(order);
+        self.map
+            .get(k)
+            .map(|n| unsafe { Cursor::new_in(self, order, n.as_ptr()) })
+    }
+
+    /// Create a mutable cursor over the ordering `order` navigated to the passed key.
+    ///
+    /// Returns [None], if the key is not in the map,
+    ///
+    /// # Panics
+    ///
+    /// If `order` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{DefaultHashBuilder, LinkedMap};
+    ///
+    /// let mut map: LinkedMap<_, _, DefaultHashBuilder, 2> = (1..=3).map(|i| (i, ())).collect();
+    /// let mut cursor = map.cursor_at_mut_in(1, &3).unwrap();
+    /// cursor.insert_after(4, ());
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4]);
+    /// map.cursor_at_mut_in(1, &1).unwrap().insert_after(5, ());
+    /// assert_eq!(map.iter_in(1).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 5, 2, 3, 4]);
+    /// assert_eq!(map.iter_in(0).map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn cursor_at_mut_in(&mut self, order: usize, k: &K) -> Option<CursorMut<'_, K, V, S, N>> {
+        Self::assert_order(order);
+        self.map
+            .get(k)
+            .copied()
+            .map(|n| unsafe { CursorMut::new_in(self, order, n.as_ptr()) })
+    }
+}
+
+impl<K, V, S> LinkedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Iterate the list from the entry of `k` to the tail.
+    ///
+    /// Returns [None], if the key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// assert_eq!(map.iter_from(&3).unwrap().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 4, 5]);
+    /// assert_eq!(map.iter_from(&3).unwrap().rev().map(|(k, _)| *k).collect::<Vec<_>>(), [5, 4, 3]);
+    /// ```
+    #[inline]
+    pub fn iter_from(&self, k: &K) -> Option<Range<'_, K, V>> {
+        self.map
+            .get(k)
+            .map(|n| unsafe { Range::new(n.as_ptr(), self.list.tail(0), self.len()) })
+    }
+
+    /// Iterate the list from the entry of `k` to the head.
+    ///
+    /// Returns [None], if the key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// assert_eq!(map.iter_rev_from(&3).unwrap().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 2, 1]);
+    /// ```
+    #[inline]
+    pub fn iter_rev_from(&self, k: &K) -> Option<Rev<Range<'_, K, V>>> {
+        self.map
+            .get(k)
+            .map(|n| unsafe { Range::new(self.list.head(0), n.as_ptr(), self.len()) }.rev())
+    }
+
+    /// Iterate the run of entries from `start` inclusively to `end` exclusively.
+    ///
+    /// The iterator is empty, if `end` does not come after `start`.
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// assert_eq!(map.range(&2, &4).unwrap().map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3]);
+    /// assert_eq!(map.range(&4, &2).unwrap().count(), 0);
+    /// assert!(map.range(&4, &6).is_none());
+    /// ```
+    #[inline]
+    pub fn range(&self, start: &K, end: &K) -> Option<Range<'_, K, V>> {
+        self.run_bounds(start, end, false)
+            .map(|(front, back)| unsafe { Range::new(front, back, self.len()) })
+    }
+
+    /// Iterate the run of entries from `start` to `end` inclusively.
+    ///
+    /// The iterator is empty, if `end` comes before `start`.
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// let mut range = map.range_inclusive(&2, &4).unwrap();
+    /// assert_eq!(range.next_back(), Some((&4, &())));
+    /// assert_eq!(range.map(|(k, _)| *k).collect::<Vec<_>>(), [2, 3]);
+    /// ```
+    #[inline]
+    pub fn range_inclusive(&self, start: &K, end: &K) -> Option<Range<'_, K, V>> {
+        self.run_bounds(start, end, true)
+            .map(|(front, back)| unsafe { Range::new(front, back, self.len()) })
+    }
+
+    /// Mutably iterate the run of entries from `start` inclusively to `end` exclusively.
+    ///
+    /// The iterator is empty, if `end` does not come after `start`.
+    /// Returns [None], if either key is not in the map.
+    #[inline]
+    pub fn range_mut(&mut self, start: &K, end: &K) -> Option<RangeMut<'_, K, V>> {
+        self.run_bounds(start, end, false)
+            .map(|(front, back)| unsafe { RangeMut::new(front, back, self.len()) })
+    }
+
+    /// Mutably iterate the run of entries from `start` to `end` inclusively.
+    ///
+    /// The iterator is empty, if `end` comes before `start`.
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, i)).collect();
+    /// for (_, v) in map.range_inclusive_mut(&2, &4).unwrap() {
+    ///     *v *= 10;
+    /// }
+    /// assert_eq!(map.iter().map(|(_, v)| *v).collect::<Vec<_>>(), [1, 20, 30, 40, 5]);
+    /// ```
+    #[inline]
+    pub fn range_inclusive_mut(&mut self, start: &K, end: &K) -> Option<RangeMut<'_, K, V>> {
+        self.run_bounds(start, end, true)
+            .map(|(front, back)| unsafe { RangeMut::new(front, back, self.len()) })
+    }
+
+    /// Remove the run of entries from `start` inclusively to `end` exclusively and iterate over them.
+    ///
+    /// The run is unlinked from the list in O(1) and its keys are removed from the hash index right away, so
+    /// entries not yielded are still removed from the map. If the saved node is in the run, it is cleared.
+    ///
+    /// The iterator is empty, if `end` does not come after `start`.
+    /// Returns [None], if either key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// assert_eq!(map.drain_range(&2, &4).unwrap().map(|(k, _)| k).collect::<Vec<_>>(), [2, 3]);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 4, 5]);
+    /// ```
+    #[inline]
+    pub fn drain_range(&mut self, start: &K, end: &K) -> Option<Drain<K, V>> {
+        self.run_bounds(start, end, false)
+            .map(|(front, back)| self.drain_run(front, back))
+    }
+
+    /// Remove the run of entries from `start` to `end` inclusively and iterate over them.
+    ///
+    /// See [drain_range](LinkedMap::drain_range) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut map: LinkedMap<_, _> = (1..=5).map(|i| (i, ())).collect();
+    /// drop(map.drain_range_inclusive(&2, &4));
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 5]);
+    /// assert_eq!(map.len(), 2);
+    /// ```
+    #[inline]
+    pub fn drain_range_inclusive(&mut self, start: &K, end: &K) -> Option<Drain<K, V>> {
+        self.run_bounds(start, end, true)
+            .map(|(front, back)| self.drain_run(front, back))
+    }
+
+    /// Resolve the first and last node of a run between two keys.
+    ///
+    /// Returns [None], if either key is not in the map.
+    fn run_bounds(&self, start: &K, end: &K, inclusive: bool) -> Option<RunBounds<K, V>> {
+        let (start, end) = (*self.map.get(start)?, *self.map.get(end)?);
+        Some(
+            match (unsafe { self.list.cmp_position(0, start, end) }, inclusive) {
+                (Ordering::Less, false) => (start.as_ptr(), unsafe { end.as_ref() }.previous(0)),
+                (Ordering::Less | Ordering::Equal, true) => (start.as_ptr(), end.as_ptr()),
+                _ => (null_mut(), null_mut()),
+            },
+        )
+    }
+
+    /// Detach a run of nodes from `front` to `back`, remove their keys from the index and return a draining
+    /// iterator over them. Both nodes must be null for an empty run.
+    fn drain_run(&mut self, front: *mut Node<K, V>, back: *mut Node<K, V>) -> Drain<K, V> {
+        let (front, back) = match (NonNull::new(front), NonNull::new(back)) {
+            (Some(front), Some(back)) => (front, back),
+            _ => return Drain::new(LinkedList::new(), 0),
+        };
+
+        let list = unsafe { self.list.detach_range(front, back) };
+        let mut len = 0;
+        let mut next = list.head(0);
+        while let Some(node) = unsafe { next.as_ref() } {
+            self.map.remove(&node.key);
+            if self.saved == next {
+                self.saved = null_mut();
+            }
+            len += 1;
+            next = node.next(0);
+        }
+        self.debug_check_invariants();
+        Drain::new(list, len)
+    }
+}
+
+impl<K, V, S> LinkedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher + Clone,
+{
+    /// Split the map in two at the given key.
+    ///
+    /// Returns a new map containing the entry of `k` and all entries after it, with their keys moved to the new
+    /// map. If the saved node is among the detached nodes, it is saved on the new map instead.
+    ///
+    /// Returns an empty map, if the key is not in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMap;
+    ///
+    /// let mut a: LinkedMap<_, _> = (1..=5).map(|i| (i, i * 10)).collect();
+    /// let b = a.split_off(&3);
+    /// assert_eq!(a.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2]);
+    /// assert_eq!(b.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [3, 4, 5]);
+    ///
+    /// assert!(a.split_off(&3).is_empty());
+    /// ```
+    pub fn split_off(&mut self, k: &K) -> Self {
+        match self.map.get(k).copied() {
+            Some(node) => {
+                let before = unsafe { self.list.split_before(node) };
+                let detached = std::mem::replace(&mut self.list, before);
+                self.adopt_detached(detached)
+            }
+            None => Self::with_hasher(self.map.hasher().clone()),
+        }
+    }
+}
+
+impl<K, V, S> LinkedMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Construct a new map out of a list of nodes detached from this one, moving their keys to the new map's
+    /// index.
+    ///
+    /// The saved node is transferred to the new map, if it is one of the detached nodes.
+    pub(crate) fn adopt_detached(&mut self, list: LinkedList<K, V>) -> Self
+    where
+        S: Clone,
+    {
+        let mut detached = Self::with_hasher(self.map.hasher().clone());
+        let mut next = list.head(0);
+        while let Some(node) = unsafe { next.as_ref() } {
+            if let Some((k, ptr)) = self.map.remove_entry(&node.key) {
+                detached.map.insert(k, ptr);
+            }
+            if self.saved == next {
+                detached.saved = next;
+                self.saved = null_mut();
+            }
+            next = node.next(0);
+        }
+        detached.list = list;
+        self.debug_check_invariants();
+        detached.debug_check_invariants();
+        detached
+    }
+
+    /// Move the entries of `other` into this map, linking them after the `after` node, or to the start of the
+    /// list, if `after` is null.
+    ///
+    /// See [SplicePolicy] for how keys present in both maps are handled.
+    ///
+    /// If the saved node of `other` is moved and this map has no saved node, the moved entry becomes saved on this
+    /// map, mirroring how splitting moves the saved node into the new map.
+    ///
+    /// # SAFETY
+    ///
+    /// `after` must be null or belong to this map.
+    pub(crate) unsafe fn splice(
+        &mut self,
+        after: *mut Node<K, V>,
+        other: &mut Self,
+        policy: SplicePolicy,
+    ) -> Result<(), SpliceError<K>> {
+        if policy == SplicePolicy::Error {
+            let mut next = other.list.head(0);
+            while let Some(node) = next.as_ref() {
+                if self.map.contains_key(&node.key) {
+                    return Err(SpliceError {
+                        key: node.key.clone(),
+                    });
+                }
+                next = node.next(0);
+            }
+        }
+
+        self.splice_nodes(after, other, policy == SplicePolicy::Skip);
+        Ok(())
+    }
+
+    /// Move the entries of `other` into this map, linking them after the `after` node, or to the start of the
+    /// list, if `after` is null. Entries of keys present in both maps are left in `other`, if `skip_present` is set,
+    /// and overwrite the existing entries otherwise.
+    ///
+    /// The nodes of `other` stay linked and its whole list is relinked in O(1) and relabeled in one pass, so only the
+    /// hash index entries and the nodes of keys present in both maps are moved individually.
+    ///
+    /// # SAFETY
+    ///
+    /// `after` must be null or belong to this map.
+    pub(crate) unsafe fn splice_nodes(
+        &mut self,
+        mut after: *mut Node<K, V>,
+        other: &mut Self,
+        skip_present: bool,
+    ) {
+        let mut skipped = LinkedList::new();
+        let mut next = other.list.head(0);
+        while let Some(node) = NonNull::new(next) {
+            next = node.as_ref().next(0);
+            let moved = match self.map.get(&node.as_ref().key).copied() {
+                Some(_) if skip_present => {
+                    other.list.remove(node);
+                    skipped.append_node(node);
+                    continue;
+                }
+                Some(mut existing) => {
+                    // Reuse the existing node in place of the other one, so pointers to it stay valid
+                    if existing.as_ptr() == after {
+                        after = existing.as_ref().previous(0);
+                    }
+                    self.list.remove(existing);
+                    other.list.insert_after(0, existing, node);
+                    other.list.remove(node);
+                    other.map.remove(&node.as_ref().key);
+                    existing.as_mut().val = Box::from_raw(node.as_ptr()).val;
+                    existing
+                }
+                None => {
+                    if let Some((k, ptr)) = other.map.remove_entry(&node.as_ref().key) {
+                        self.map.insert(k, ptr);
+                    }
+                    node
+                }
+            };
+            // The bookmark moves along with its entry, unless this map has its own
+            if other.saved == node.as_ptr() {
+                other.saved = null_mut();
+                if self.saved.is_null() {
+                    self.saved = moved.as_ptr();
+                }
+            }
+        }
+
+        let run = std::mem::replace(&mut other.list, skipped);
+        self.list.splice_after(after, run);
+        self.debug_check_invariants();
+        other.debug_check_invariants();
+    }
+}
+
+/// Structural defect of a [LinkedMap] found by [LinkedMap::check_invariants]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// The node at `index` was already reached earlier, when walking the list from the head
+    Cycle {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The `previous` link of the node at `index` does not point at the node linking to it
+    AsymmetricLink {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The last node reachable from the head is not the tail
+    TailMismatch,
+
+    /// The order label of the node at `index` is not greater than that of the previous node
+    LabelOrder {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The number of nodes in the list differs from the number of entries in the hash index
+    LengthMismatch {
+        /// Number of nodes reachable from the head
+        list: usize,
+
+        /// Number of entries in the hash index
+        map: usize,
+    },
+
+    /// An entry of the hash index points at a node, that is not in the list
+    UnlinkedIndexEntry,
+
+    /// An entry of the hash index points at the node at `index`, which has a different key
+    KeyMismatch {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The saved node is not in the list
+    SavedUnreachable,
+
+    /// The same-key links of a [LinkedMultiMap](crate::LinkedMultiMap) are broken at the node at `index`, or the
+    /// ends or length of the key's chain in the index do not match its nodes
+    KeyChain {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The position index of an [IndexedLinkedMap](crate::IndexedLinkedMap) does not hold the node at `index` at
+    /// that position, or its tree is malformed there
+    PositionIndex {
+        /// Position of the node in the list
+        index: usize,
+    },
+
+    /// The ordering `order` of a map with multiple orderings does not hold the same nodes as the first ordering
+    OrderingMismatch {
+        /// Index of the ordering
+        order: usize,
+    },
+}
+
+impl std::fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycle { index } => write!(f, "cycle at node {index}"),
+            Self::AsymmetricLink { index } => write!(f, "asymmetric links at node {index}"),
+            Self::TailMismatch => write!(f, "last node is not the tail"),
+            Self::LabelOrder { index } => write!(f, "order label not increasing at node {index}"),
+            Self::LengthMismatch { list, map } => {
+                write!(f, "list has {list} nodes, but index has {map} entries")
+            }
+            Self::UnlinkedIndexEntry => write!(f, "index entry points at node not in list"),
+            Self::KeyMismatch { index } => {
+                write!(f, "index entry key differs from key of node {index}")
+            }
+            Self::SavedUnreachable => write!(f, "saved node not in list"),
+            Self::KeyChain { index } => write!(f, "same-key chain broken at node {index}"),
+            Self::PositionIndex { index } => write!(f, "position index broken at node {index}"),
+            Self::OrderingMismatch { order } => {
+                write!(
+                    f,
+                    "ordering {order} holds different nodes than the first ordering"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
+
+/// Node to start scanning from, when searching for the sorted position of a new entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanFrom<'a, K> {
+    /// Scan from the start of the list
+    Head,
+
+    /// Scan from the end of the list
+    Tail,
+
+    /// Scan from the entry of the key. Falls back to [ScanFrom::Tail], if the key is not in the map.
+    Key(&'a K),
+}
+
+/// Policy for handling keys present in both maps, when splicing one [LinkedMap] into another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplicePolicy {
+    /// Return [SpliceError] and leave both maps unchanged
+    Error,
+
+    /// Replace the value of the existing entry and move it to its position in the spliced run
+    Overwrite,
+
+    /// Keep the existing entry as is and leave the other entry in the source map
+    Skip,
+}
+
+/// Returned by splicing with [SplicePolicy::Error], when a key is present in both maps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpliceError<K> {
+    /// First key of the source map found to also be present in the destination map
+    pub key: K,
+}
+
+impl<K: std::fmt::Debug> std::fmt::Display for SpliceError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "key {:?} present in both maps", self.key)
+    }
+}
+
+impl<K: std::fmt::Debug> std::error::Error for SpliceError<K> {}
+
+/// Returned by [LinkedMap::rekey] and [CursorMut::replace_key], when the key of an entry could not be replaced.
+///
+/// Both variants hand back the new key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RekeyError<K> {
+    /// The entry to rekey is not in the map
+    NotFound(K),
+
+    /// The new key is already present in the map under a different entry
+    Occupied(K),
+}
+
+impl<K: std::fmt::Debug> std::fmt::Display for RekeyError<K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotFound(k) => write!(f, "entry to rekey to {k:?} not found"),
+            Self::Occupied(k) => write!(f, "new key {k:?} already present in map"),
+        }
+    }
+}
+
+impl<K: std::fmt::Debug> std::error::Error for RekeyError<K> {}
+
+impl<K, V, S, const N: usize> Default for LinkedMap<K, V, S, N>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            list: LinkedList::new(),
+            map: Default::default(),
+            saved: null_mut(),
+        }
+    }
+}
+
+// TODO: port as many methods and trait impls of the stdlib linked_list and hashbrown::HashMap as possible
+
+impl<K, V, S> Clone for LinkedMap<K, V, S>
 where
     K: Eq + Hash + Clone + 'static,
     V: Clone + 'static,
@@ -439,7 +2030,19 @@ where
     }
 }
 
-impl<K, V, S> FromIterator<(K, V)> for LinkedMap<K, V, S>
+impl<K, V, S, const N: usize> std::fmt::Debug for LinkedMap<K, V, S, N>
+where
+    K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+    V: std::fmt::Debug + 'static,
+    S: BuildHasher,
+{
+    /// Formats the entries in ordering `0`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S, const N: usize> FromIterator<(K, V)> for LinkedMap<K, V, S, N>
 where
     K: Eq + Hash + Clone + 'static,
     V: 'static,
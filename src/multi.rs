@@ -0,0 +1,791 @@
+use std::{
+    hash::{BuildHasher, Hash},
+    ptr::{null_mut, NonNull},
+};
+
+use hashbrown::{hash_map::Entry, HashMap};
+
+use crate::{
+    linked_list::{list::Node, LinkedList},
+    map::DefaultHashBuilder,
+    InvariantViolation,
+};
+
+/// Value of a [LinkedMultiMap] node, linked to the neighbouring values of the same key
+struct Slot<K, V> {
+    /// Stored value
+    val: V,
+
+    /// Previous node with the same key. Null for the first value of the key.
+    previous_same: *mut MultiNode<K, V>,
+
+    /// Next node with the same key. Null for the last value of the key.
+    next_same: *mut MultiNode<K, V>,
+}
+
+/// Node of the list of a [LinkedMultiMap]
+type MultiNode<K, V> = Node<K, Slot<K, V>>;
+
+/// Ends and length of the sub-chain of nodes of a key
+struct Chain<K, V> {
+    /// Earliest node of the key in the list
+    first: NonNull<MultiNode<K, V>>,
+
+    /// Latest node of the key in the list
+    last: NonNull<MultiNode<K, V>>,
+
+    /// Number of nodes of the key
+    len: usize,
+}
+
+/// Key-value store holding any number of values per key in one global list, with each key's values additionally
+/// linked in a sub-chain in list order.
+///
+/// Accessing the first or last value of a key is O(1) and iterating the values of a key only visits nodes of that
+/// key.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::LinkedMultiMap;
+///
+/// let mut log = LinkedMultiMap::new();
+/// log.append("session-a", "login");
+/// log.append("session-b", "login");
+/// log.append("session-a", "click");
+/// log.append("session-a", "logout");
+///
+/// assert_eq!(log.get_all(&"session-a").collect::<Vec<_>>(), [&"login", &"click", &"logout"]);
+/// assert_eq!(log.last(&"session-a"), Some(&"logout"));
+/// assert_eq!(log.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["session-a", "session-b", "session-a", "session-a"]);
+/// ```
+pub struct LinkedMultiMap<K, V, S = DefaultHashBuilder> {
+    /// Stores the global order of all values
+    list: LinkedList<K, Slot<K, V>>,
+
+    /// Stores the sub-chain of every key
+    map: HashMap<K, Chain<K, V>, S>,
+
+    /// Total number of values
+    len: usize,
+}
+
+impl<K, V> LinkedMultiMap<K, V, DefaultHashBuilder> {
+    /// Create a new empty [LinkedMultiMap]
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+impl<K, V, S> Default for LinkedMultiMap<K, V, S>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<K, V, S> LinkedMultiMap<K, V, S> {
+    /// Create a new empty [LinkedMultiMap], which will use the given hash builder to hash keys
+    #[inline]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            list: LinkedList::new(),
+            map: HashMap::with_hasher(hash_builder),
+            len: 0,
+        }
+    }
+
+    /// Returns the total number of values in the map
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map contains no values
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of distinct keys in the map
+    #[inline]
+    pub fn keys_len(&self) -> usize {
+        self.map.len()
+    }
+}
+
+impl<K, V, S> LinkedMultiMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    /// Insert a value at the end of the list, making it the last value of its key
+    pub fn append(&mut self, k: K, v: V) {
+        let node = self.list.append(k.clone(), Slot::new(v));
+        match self.map.entry(k) {
+            Entry::Occupied(mut e) => {
+                let chain = e.get_mut();
+                unsafe { Slot::link(chain.last.as_ptr(), node.as_ptr()) };
+                chain.last = node;
+                chain.len += 1;
+            }
+            Entry::Vacant(e) => {
+                e.insert(Chain::new(node));
+            }
+        }
+        self.len += 1;
+        self.debug_check_invariants();
+    }
+
+    /// Insert a value at the start of the list, making it the first value of its key
+    pub fn prepend(&mut self, k: K, v: V) {
+        let node = self.list.prepend(k.clone(), Slot::new(v));
+        match self.map.entry(k) {
+            Entry::Occupied(mut e) => {
+                let chain = e.get_mut();
+                unsafe { Slot::link(node.as_ptr(), chain.first.as_ptr()) };
+                chain.first = node;
+                chain.len += 1;
+            }
+            Entry::Vacant(e) => {
+                e.insert(Chain::new(node));
+            }
+        }
+        self.len += 1;
+        self.debug_check_invariants();
+    }
+
+    /// Returns `true`, if the map contains any values for the key
+    #[inline]
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.map.contains_key(k)
+    }
+
+    /// Returns the number of values of the key
+    #[inline]
+    pub fn count(&self, k: &K) -> usize {
+        self.map.get(k).map_or(0, |c| c.len)
+    }
+
+    /// Return the earliest value of the key in O(1)
+    #[inline]
+    pub fn first(&self, k: &K) -> Option<&V> {
+        self.map
+            .get(k)
+            .map(|c| unsafe { &(*c.first.as_ptr()).val.val })
+    }
+
+    /// Return the latest value of the key in O(1)
+    #[inline]
+    pub fn last(&self, k: &K) -> Option<&V> {
+        self.map
+            .get(k)
+            .map(|c| unsafe { &(*c.last.as_ptr()).val.val })
+    }
+
+    /// Iterate the values of the key in list order. Only visits nodes of the key.
+    pub fn get_all(&self, k: &K) -> impl Iterator<Item = &V> {
+        let mut next = self.map.get(k).map_or(null_mut(), |c| c.first.as_ptr());
+        std::iter::from_fn(move || {
+            let n = NonNull::new(next)?.as_ptr();
+            unsafe {
+                next = (*n).val.next_same;
+                Some(&(*n).val.val)
+            }
+        })
+    }
+
+    /// Remove all values of the key and return them in list order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedMultiMap;
+    ///
+    /// let mut map: LinkedMultiMap<_, _> = [(1, 'a'), (2, 'b'), (1, 'c')].into_iter().collect();
+    /// assert_eq!(map.remove_all(&1), ['a', 'c']);
+    /// assert!(map.remove_all(&1).is_empty());
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [(&2, &'b')]);
+    /// ```
+    pub fn remove_all(&mut self, k: &K) -> Vec<V> {
+        let chain = match self.map.remove(k) {
+            Some(chain) => chain,
+            None => return Vec::new(),
+        };
+
+        let mut values = Vec::with_capacity(chain.len);
+        let mut next = chain.first.as_ptr();
+        while let Some(node) = NonNull::new(next) {
+            next = unsafe { (*node.as_ptr()).val.next_same };
+            self.list.remove(node);
+            let node = unsafe { Box::from_raw(node.as_ptr()) };
+            values.push(node.val.val);
+        }
+        self.len -= chain.len;
+        self.debug_check_invariants();
+        values
+    }
+
+    /// Remove the earliest value of the key and return it
+    #[inline]
+    pub fn remove_first(&mut self, k: &K) -> Option<V> {
+        let first = self.map.get(k)?.first;
+        Some(unsafe { self.remove_node(first) }.1)
+    }
+
+    /// Remove the first entry of the list and return it
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<(K, V)> {
+        NonNull::new(self.list.head(0)).map(|node| unsafe { self.remove_node(node) })
+    }
+
+    /// Remove the last entry of the list and return it
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<(K, V)> {
+        NonNull::new(self.list.tail(0)).map(|node| unsafe { self.remove_node(node) })
+    }
+
+    /// Unlink a node from the list and its key's sub-chain, free it and return its key and value.
+    ///
+    /// # SAFETY
+    ///
+    /// `node` must belong to this map.
+    unsafe fn remove_node(&mut self, node: NonNull<MultiNode<K, V>>) -> (K, V) {
+        let n = node.as_ptr();
+        let (previous, next) = ((*n).val.previous_same, (*n).val.next_same);
+        match (NonNull::new(previous), NonNull::new(next)) {
+            (None, None) => {
+                self.map.remove(&(*n).key);
+            }
+            (previous_node, next_node) => {
+                let chain = self.map.get_mut(&(*n).key).unwrap();
+                match previous_node {
+                    Some(p) => (*p.as_ptr()).val.next_same = next,
+                    None => chain.first = next_node.unwrap(),
+                }
+                match next_node {
+                    Some(nx) => (*nx.as_ptr()).val.previous_same = previous,
+                    None => chain.last = previous_node.unwrap(),
+                }
+                chain.len -= 1;
+            }
+        }
+
+        self.list.remove(node);
+        self.len -= 1;
+        self.debug_check_invariants();
+        let node = Box::from_raw(n);
+        (node.key, node.val.val)
+    }
+
+    /// Iterate all entries in list order
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut next = self.list.head(0);
+        std::iter::from_fn(move || {
+            let n = NonNull::new(next)?.as_ptr();
+            unsafe {
+                next = Node::next_of(n, 0);
+                Some((&(*n).key, &(*n).val.val))
+            }
+        })
+    }
+
+    /// Iterate all entries in reverse list order
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        let mut next = self.list.tail(0);
+        std::iter::from_fn(move || {
+            let n = NonNull::new(next)?.as_ptr();
+            unsafe {
+                next = Node::previous_of(n, 0);
+                Some((&(*n).key, &(*n).val.val))
+            }
+        })
+    }
+
+    /// Create a cursor over the map set to the first entry
+    #[inline]
+    pub fn cursor(&self) -> MultiCursor<'_, K, V, S> {
+        MultiCursor {
+            parent: self,
+            current: self.list.head(0),
+        }
+    }
+
+    /// Create a mutable cursor over the map set to the first entry
+    #[inline]
+    pub fn cursor_mut(&mut self) -> MultiCursorMut<'_, K, V, S> {
+        let current = self.list.head(0);
+        MultiCursorMut {
+            parent: self,
+            current,
+        }
+    }
+
+    /// Create a cursor navigated to the first value of the key.
+    ///
+    /// Returns [None], if the key is not in the map.
+    #[inline]
+    pub fn cursor_at(&self, k: &K) -> Option<MultiCursor<'_, K, V, S>> {
+        self.map.get(k).map(|c| MultiCursor {
+            parent: self,
+            current: c.first.as_ptr(),
+        })
+    }
+
+    /// Create a mutable cursor navigated to the first value of the key.
+    ///
+    /// Returns [None], if the key is not in the map.
+    #[inline]
+    pub fn cursor_at_mut(&mut self, k: &K) -> Option<MultiCursorMut<'_, K, V, S>> {
+        let current = self.map.get(k)?.first.as_ptr();
+        Some(MultiCursorMut {
+            parent: self,
+            current,
+        })
+    }
+
+    /// Check the structural invariants of the map.
+    ///
+    /// Besides the checks of [LinkedMap::check_invariants](crate::LinkedMap::check_invariants), walks the sub-chain
+    /// of every key, checking that it only contains nodes of the key in list order and that its ends and length
+    /// match the index.
+    pub fn check_invariants(&self) -> Result<(), InvariantViolation> {
+        let positions = self.list.check_links()?;
+        if positions.len() != self.len {
+            return Err(InvariantViolation::LengthMismatch {
+                list: positions.len(),
+                map: self.len,
+            });
+        }
+
+        let mut chained = 0;
+        for (k, chain) in self.map.iter() {
+            let mut previous = null_mut();
+            let mut previous_index = None;
+            let mut next = chain.first.as_ptr();
+            let mut len = 0;
+            while let Some(node) = unsafe { next.as_ref() } {
+                let index = *positions
+                    .get(&next)
+                    .ok_or(InvariantViolation::UnlinkedIndexEntry)?;
+                if &node.key != k {
+                    return Err(InvariantViolation::KeyMismatch { index });
+                }
+                // Increasing positions also rule out cycles
+                if node.val.previous_same != previous || previous_index >= Some(index) {
+                    return Err(InvariantViolation::KeyChain { index });
+                }
+                previous = next;
+                previous_index = Some(index);
+                next = node.val.next_same;
+                len += 1;
+            }
+            if previous != chain.last.as_ptr() || len != chain.len {
+                return Err(InvariantViolation::KeyChain {
+                    index: previous_index.unwrap_or_default(),
+                });
+            }
+            chained += len;
+        }
+        if chained != self.len {
+            return Err(InvariantViolation::LengthMismatch {
+                list: positions.len(),
+                map: chained,
+            });
+        }
+        Ok(())
+    }
+
+    /// Panic, if the map is structurally invalid. Only checks with the `debug-invariants` feature enabled.
+    #[inline]
+    fn debug_check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(e) = self.check_invariants() {
+            panic!("LinkedMultiMap invariant violated: {e}");
+        }
+    }
+}
+
+impl<K, V, S> std::fmt::Debug for LinkedMultiMap<K, V, S>
+where
+    K: Eq + Hash + Clone + std::fmt::Debug + 'static,
+    V: std::fmt::Debug + 'static,
+    S: BuildHasher,
+{
+    /// Formats the entries in list order
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for LinkedMultiMap<K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher + Default,
+{
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut map = Self::default();
+        for (k, v) in iter {
+            map.append(k, v);
+        }
+        map
+    }
+}
+
+impl<K, V> Slot<K, V> {
+    /// Create a value not linked to any other values of its key
+    #[inline]
+    fn new(val: V) -> Self {
+        Self {
+            val,
+            previous_same: null_mut(),
+            next_same: null_mut(),
+        }
+    }
+
+    /// Link `next` right after `previous` in the sub-chain of their key. Both must be the respective ends of their
+    /// chains.
+    ///
+    /// # SAFETY
+    ///
+    /// Both pointers must be valid and distinct.
+    #[inline]
+    unsafe fn link(previous: *mut MultiNode<K, V>, next: *mut MultiNode<K, V>) {
+        (*previous).val.next_same = next;
+        (*next).val.previous_same = previous;
+    }
+}
+
+impl<K, V> Chain<K, V> {
+    /// Create a chain of a single node
+    #[inline]
+    fn new(node: NonNull<MultiNode<K, V>>) -> Self {
+        Self {
+            first: node,
+            last: node,
+            len: 1,
+        }
+    }
+}
+
+/// Implements navigation common to both [MultiCursor] and [MultiCursorMut].
+/// `$nav` is the type of key-value pairs returned by navigation methods.
+macro_rules! impl_multi_common {
+    ($nav:ty) => {
+        /// Navigate to the first entry of the list
+        #[inline]
+        pub fn to_front(&mut self) {
+            self.current = self.parent.list.head(0);
+        }
+
+        /// Navigate to the last entry of the list
+        #[inline]
+        pub fn to_back(&mut self) {
+            self.current = self.parent.list.tail(0);
+        }
+
+        /// Return the key of the current entry. Only returns [None], if the map is empty.
+        #[inline]
+        pub fn key(&self) -> Option<&K> {
+            NonNull::new(self.current).map(|n| unsafe { &(*n.as_ptr()).key })
+        }
+
+        /// Return the value of the current entry. Only returns [None], if the map is empty.
+        #[inline]
+        pub fn value(&self) -> Option<&V> {
+            NonNull::new(self.current).map(|n| unsafe { &(*n.as_ptr()).val.val })
+        }
+
+        /// Returns the total number of values in the map
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.parent.len()
+        }
+
+        /// Returns `true` if the map contains no values
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.parent.is_empty()
+        }
+
+        /// Navigate to the first value of the key and return it.
+        /// Returns [None] and stays in place, if the key is not in the map.
+        #[inline]
+        pub fn to_key(&mut self, k: &K) -> Option<$nav> {
+            let first = self.parent.map.get(k)?.first;
+            Some(self.navigate(first))
+        }
+
+        /// Advance the cursor to the next entry of the list and return it.
+        /// Returns [None] and stays in place, if the cursor is at the last entry.
+        #[inline]
+        #[allow(clippy::should_implement_trait)]
+        pub fn next(&mut self) -> Option<$nav> {
+            let next = NonNull::new(unsafe { Node::next_of(self.current, 0) })?;
+            Some(self.navigate(next))
+        }
+
+        /// Move the cursor to the previous entry of the list and return it.
+        /// Returns [None] and stays in place, if the cursor is at the first entry.
+        #[inline]
+        pub fn previous(&mut self) -> Option<$nav> {
+            let previous = NonNull::new(unsafe { Node::previous_of(self.current, 0) })?;
+            Some(self.navigate(previous))
+        }
+
+        /// Advance the cursor to the next value with the same key as the current one, skipping entries of other
+        /// keys in O(1), and return it.
+        /// Returns [None] and stays in place, if the current value is the last one of its key.
+        #[inline]
+        pub fn next_same_key(&mut self) -> Option<$nav> {
+            let current = NonNull::new(self.current)?;
+            let next = NonNull::new(unsafe { (*current.as_ptr()).val.next_same })?;
+            Some(self.navigate(next))
+        }
+
+        /// Move the cursor to the previous value with the same key as the current one, skipping entries of other
+        /// keys in O(1), and return it.
+        /// Returns [None] and stays in place, if the current value is the first one of its key.
+        #[inline]
+        pub fn previous_same_key(&mut self) -> Option<$nav> {
+            let current = NonNull::new(self.current)?;
+            let previous = NonNull::new(unsafe { (*current.as_ptr()).val.previous_same })?;
+            Some(self.navigate(previous))
+        }
+    };
+}
+
+/// Enables safe traversal of a [LinkedMultiMap]
+pub struct MultiCursor<'a, K, V, S> {
+    /// Parent [LinkedMultiMap]
+    parent: &'a LinkedMultiMap<K, V, S>,
+
+    /// Node the cursor is currently at. Can be null, if parent is empty.
+    current: *mut MultiNode<K, V>,
+}
+
+impl<'a, K, V, S> MultiCursor<'a, K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    impl_multi_common! {(&'a K, &'a V)}
+
+    /// Navigate to the node and return its key-value pair with the lifetime of the parent borrow
+    #[inline]
+    fn navigate(&mut self, node: NonNull<MultiNode<K, V>>) -> (&'a K, &'a V) {
+        self.current = node.as_ptr();
+        let n = node.as_ptr();
+        unsafe { (&(*n).key, &(*n).val.val) }
+    }
+}
+
+impl<'a, K, V, S> Clone for MultiCursor<'a, K, V, S> {
+    fn clone(&self) -> Self {
+        Self {
+            parent: self.parent,
+            current: self.current,
+        }
+    }
+}
+
+/// Enables safe traversal and mutation of a [LinkedMultiMap]
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::LinkedMultiMap;
+///
+/// let mut map: LinkedMultiMap<_, _> = [(1, 10), (2, 20), (1, 11), (2, 21), (1, 12)].into_iter().collect();
+/// let mut cursor = map.cursor_at_mut(&1).unwrap();
+/// assert_eq!(cursor.next_same_key(), Some((&1, &mut 11)));
+/// assert_eq!(cursor.remove_and_advance(), Some((1, 11)));
+/// assert_eq!(cursor.key_value(), Some((&2, &21)));
+/// assert_eq!(map.get_all(&1).collect::<Vec<_>>(), [&10, &12]);
+/// ```
+pub struct MultiCursorMut<'a, K, V, S> {
+    /// Parent [LinkedMultiMap]
+    parent: &'a mut LinkedMultiMap<K, V, S>,
+
+    /// Node the cursor is currently at. Can be null, if parent is empty.
+    current: *mut MultiNode<K, V>,
+}
+
+impl<'a, K, V, S> MultiCursorMut<'a, K, V, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    S: BuildHasher,
+{
+    impl_multi_common! {(&K, &mut V)}
+
+    /// Navigate to the node and return its key and a mutable reference to its value, borrowing the cursor
+    #[inline]
+    fn navigate(&mut self, node: NonNull<MultiNode<K, V>>) -> (&K, &mut V) {
+        self.current = node.as_ptr();
+        let n = node.as_ptr();
+        unsafe { (&(*n).key, &mut (*n).val.val) }
+    }
+
+    /// Return the current key-value pair. Only returns [None], if the map is empty.
+    #[inline]
+    pub fn key_value(&self) -> Option<(&K, &V)> {
+        NonNull::new(self.current).map(|n| {
+            let n = n.as_ptr();
+            unsafe { (&(*n).key, &(*n).val.val) }
+        })
+    }
+
+    /// Return a mutable reference to the current value. Only returns [None], if the map is empty.
+    #[inline]
+    pub fn value_mut(&mut self) -> Option<&mut V> {
+        NonNull::new(self.current).map(|n| unsafe { &mut (*n.as_ptr()).val.val })
+    }
+
+    /// Return a read-only cursor at the same position, that borrows this cursor
+    #[inline]
+    pub fn as_cursor(&self) -> MultiCursor<'_, K, V, S> {
+        MultiCursor {
+            parent: &*self.parent,
+            current: self.current,
+        }
+    }
+
+    /// Remove the current entry and return it.
+    /// Returns [None], if the map is empty.
+    ///
+    /// Navigates the cursor to the previous entry.
+    /// If the removed entry was the first one, navigates it to the next entry.
+    #[inline]
+    pub fn remove(&mut self) -> Option<(K, V)> {
+        let current = NonNull::new(self.current)?;
+        self.current = match unsafe { Node::previous_of(current.as_ptr(), 0) } {
+            previous if !previous.is_null() => previous,
+            _ => unsafe { Node::next_of(current.as_ptr(), 0) },
+        };
+        Some(unsafe { self.parent.remove_node(current) })
+    }
+
+    /// Remove the current entry and return it.
+    /// Returns [None], if the map is empty.
+    ///
+    /// Navigates the cursor to the next entry.
+    /// If the removed entry was the last one, navigates it to the previous entry.
+    #[inline]
+    pub fn remove_and_advance(&mut self) -> Option<(K, V)> {
+        let current = NonNull::new(self.current)?;
+        self.current = match unsafe { Node::next_of(current.as_ptr(), 0) } {
+            next if !next.is_null() => next,
+            _ => unsafe { Node::previous_of(current.as_ptr(), 0) },
+        };
+        Some(unsafe { self.parent.remove_node(current) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedMultiMap;
+    use crate::linked_list::tests::{gen_tests, steps, Rng};
+    use std::collections::VecDeque;
+
+    /// Assert the multimap matches the model of its entries in list order
+    fn compare_multi(map: &LinkedMultiMap<u32, u32>, model: &VecDeque<(u32, u32)>, k: u32) {
+        let expected: Vec<_> = model.iter().copied().collect();
+        let forward: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+        assert_eq!(forward, expected);
+        let mut backward: Vec<_> = map.iter_rev().map(|(k, v)| (*k, *v)).collect();
+        backward.reverse();
+        assert_eq!(backward, expected);
+
+        let values: Vec<_> = model.iter().filter(|e| e.0 == k).map(|e| e.1).collect();
+        assert_eq!(map.get_all(&k).copied().collect::<Vec<_>>(), values);
+        assert_eq!(map.first(&k), values.first());
+        assert_eq!(map.last(&k), values.last());
+        assert_eq!(map.count(&k), values.len());
+        if let Some(mut c) = map.cursor_at(&k) {
+            while c.next_same_key().is_some() {}
+            let mut backward = vec![*c.value().unwrap()];
+            backward.extend(std::iter::from_fn(|| {
+                c.previous_same_key().map(|(_, v)| *v)
+            }));
+            backward.reverse();
+            assert_eq!(backward, values);
+        }
+        assert_eq!(map.len(), model.len());
+        assert_eq!(map.check_invariants(), Ok(()));
+    }
+
+    gen_tests! {test_multi_map_operations}
+    fn test_multi_map_operations(seed: u64) {
+        let mut rng = Rng(seed);
+        let mut map = LinkedMultiMap::new();
+        let mut model = VecDeque::new();
+
+        for _ in 0..steps(2000) {
+            let k = rng.below(16) as u32;
+            let v = rng.next() as u32;
+            match rng.below(8) {
+                0 | 1 => {
+                    map.prepend(k, v);
+                    model.push_front((k, v));
+                }
+                2 | 3 => {
+                    map.append(k, v);
+                    model.push_back((k, v));
+                }
+                4 => {
+                    let expected: Vec<_> = model.iter().filter(|e| e.0 == k).map(|e| e.1).collect();
+                    model.retain(|e| e.0 != k);
+                    assert_eq!(map.remove_all(&k), expected);
+                }
+                5 => {
+                    let i = model.iter().position(|e| e.0 == k);
+                    assert_eq!(map.remove_first(&k), i.map(|i| model.remove(i).unwrap().1));
+                }
+                6 => {
+                    if rng.below(2) == 0 {
+                        assert_eq!(map.pop_front(), model.pop_front());
+                    } else {
+                        assert_eq!(map.pop_back(), model.pop_back());
+                    }
+                }
+                _ => {
+                    // Walk the values of the key with a cursor, removing some of them
+                    let Some(mut c) = map.cursor_at_mut(&k) else {
+                        assert!(model.iter().all(|e| e.0 != k));
+                        continue;
+                    };
+                    let mut i = model.iter().position(|e| e.0 == k).unwrap();
+                    loop {
+                        assert_eq!(c.key_value(), Some((&model[i].0, &model[i].1)));
+                        if rng.below(4) == 0 {
+                            assert_eq!(c.remove_and_advance(), model.remove(i));
+                            let i = i.min(model.len().saturating_sub(1));
+                            assert_eq!(c.key_value(), model.get(i).map(|e| (&e.0, &e.1)));
+                            break;
+                        }
+                        match model.range(i + 1..).position(|e| e.0 == k) {
+                            Some(offset) => {
+                                i += offset + 1;
+                                let next = c.next_same_key().map(|(k, v)| (*k, *v));
+                                assert_eq!(next, Some(model[i]));
+                            }
+                            None => {
+                                assert!(c.next_same_key().is_none());
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            compare_multi(&map, &model, k);
+        }
+    }
+}
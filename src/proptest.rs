@@ -0,0 +1,110 @@
+//! [proptest] strategies for generating [LinkedMap]s
+
+use std::hash::Hash;
+
+use ::proptest::{
+    collection::{hash_set, vec, SizeRange},
+    prelude::*,
+    sample::Index,
+};
+
+use crate::LinkedMap;
+
+/// Create a strategy to generate [LinkedMap]s with keys and values from `key` and `value` and a number of entries
+/// within `size`.
+///
+/// Entries are prepended or appended at random and some are moved around after all entries are inserted, so the
+/// order of a map is independent of the order its keys were generated in. Single-entry maps are generated more
+/// often than the size range alone would suggest, if `size` allows them.
+///
+/// Keys are generated as a set with [proptest::collection::hash_set], so every generated map meets the size range,
+/// but the key strategy must be able to produce enough distinct keys.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::proptest::linked_map;
+/// use proptest::{prelude::*, test_runner::TestRunner};
+///
+/// let mut runner = TestRunner::default();
+/// let strategy = linked_map(any::<u8>(), any::<u32>(), 0..32);
+/// runner
+///     .run(&strategy, |mut map| {
+///         let before: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+///         map.reverse();
+///         map.reverse();
+///         prop_assert_eq!(map.iter().map(|(k, v)| (*k, *v)).collect::<Vec<_>>(), before);
+///         Ok(())
+///     })
+///     .unwrap();
+///
+/// // Every key of the strategy is needed to fill the map
+/// let strategy = linked_map(0..16u8, any::<u32>(), 16);
+/// runner
+///     .run(&strategy, |map| {
+///         prop_assert_eq!(map.len(), 16);
+///         Ok(())
+///     })
+///     .unwrap();
+/// ```
+pub fn linked_map<K, V>(
+    key: K,
+    value: V,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = LinkedMap<K::Value, V::Value>>
+where
+    K: Strategy + Clone,
+    K::Value: Eq + Hash + Clone + 'static,
+    V: Strategy + Clone,
+    V::Value: 'static,
+{
+    let size = size.into();
+    let single = if size.start() <= 1 && 1 < size.end_excl() {
+        SizeRange::from(1)
+    } else {
+        size.clone()
+    };
+
+    let keys = prop_oneof![
+        1 => hash_set(key.clone(), single),
+        9 => hash_set(key, size),
+    ];
+    let entries = keys.prop_flat_map(move |keys| {
+        let values = vec((value.clone(), any::<bool>()), keys.len());
+        (Just(keys.into_iter().collect::<Vec<_>>()), values)
+    });
+    let moves = vec((any::<Index>(), any::<Index>(), any::<bool>()), 0..8);
+
+    (entries, moves).prop_map(|((keys, values), moves)| {
+        let mut map = LinkedMap::new();
+        for (k, (v, front)) in keys.into_iter().zip(values) {
+            if front {
+                map.prepend(k, v);
+            } else {
+                map.append(k, v);
+            }
+        }
+        reorder(&mut map, moves);
+        map
+    })
+}
+
+/// Move entries before or after other entries, both picked by their position
+fn reorder<K, V>(map: &mut LinkedMap<K, V>, moves: Vec<(Index, Index, bool)>)
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+{
+    if map.is_empty() {
+        return;
+    }
+    for (k, anchor, after) in moves {
+        let keys: Vec<_> = map.iter().map(|(k, _)| k.clone()).collect();
+        let (k, anchor) = (k.get(&keys), anchor.get(&keys));
+        if after {
+            map.move_after(k, anchor);
+        } else {
+            map.move_before(k, anchor);
+        }
+    }
+}
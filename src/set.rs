@@ -0,0 +1,654 @@
+use std::hash::{BuildHasher, Hash};
+
+use crate::{map::DefaultHashBuilder, Cursor, CursorMut, InsertOutcome, LinkedMap};
+
+/// Ordered hash set built on a [LinkedMap] with unit values.
+///
+/// Provides O(1) membership tests, inserts at either end, removal and reordering, as well as cursors over the
+/// values in list order.
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::LinkedSet;
+///
+/// let mut set = LinkedSet::new();
+/// set.insert_back("b");
+/// set.insert_back("c");
+/// set.insert_front("a");
+/// assert!(set.contains(&"b"));
+///
+/// set.move_to_front(&"c");
+/// assert_eq!(set.iter().copied().collect::<Vec<_>>(), ["c", "a", "b"]);
+/// ```
+pub struct LinkedSet<T, S = DefaultHashBuilder> {
+    /// Underlying map storing the values as keys
+    inner: LinkedMap<T, (), S>,
+}
+
+impl<T> LinkedSet<T, DefaultHashBuilder> {
+    /// Create a new empty [LinkedSet]
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<T, S> LinkedSet<T, S> {
+    /// Create a new empty [LinkedSet], which will use the given hash builder to hash values
+    #[inline]
+    pub const fn with_hasher(hash_builder: S) -> Self {
+        Self {
+            inner: LinkedMap::with_hasher(hash_builder),
+        }
+    }
+
+    /// Wrap an existing [LinkedMap], keeping the order of its keys
+    #[inline]
+    pub fn from_map(inner: LinkedMap<T, (), S>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the number of values in the set
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the set contains no values
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the underlying [LinkedMap]
+    #[inline]
+    pub fn as_map(&self) -> &LinkedMap<T, (), S> {
+        &self.inner
+    }
+
+    /// Consume the wrapper and return the underlying [LinkedMap]
+    #[inline]
+    pub fn into_inner(self) -> LinkedMap<T, (), S> {
+        self.inner
+    }
+}
+
+impl<T, S> LinkedSet<T, S>
+where
+    T: Eq + Hash + Clone + 'static,
+    S: BuildHasher,
+{
+    /// Insert a value at the front of the set.
+    ///
+    /// Returns `true`, if the value was not in the set. Otherwise the value is moved to the front.
+    #[inline]
+    pub fn insert_front(&mut self, value: T) -> bool {
+        self.inner.prepend(value, ()).is_none()
+    }
+
+    /// Insert a value at the back of the set.
+    ///
+    /// Returns `true`, if the value was not in the set. Otherwise the value is moved to the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedSet;
+    ///
+    /// let mut set = LinkedSet::new();
+    /// assert!(set.insert_back(1));
+    /// assert!(set.insert_back(2));
+    /// assert!(!set.insert_back(1));
+    /// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [2, 1]);
+    /// ```
+    #[inline]
+    pub fn insert_back(&mut self, value: T) -> bool {
+        self.inner.append(value, ()).is_none()
+    }
+
+    /// Returns `true`, if the set contains the value
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        self.inner.contains_key(value)
+    }
+
+    /// Remove a value from the set. Returns `true`, if the value was in the set.
+    #[inline]
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.inner.remove(value).is_some()
+    }
+
+    /// Move a value to the front of the set in O(1).
+    ///
+    /// Returns `false`, if the value is not in the set.
+    pub fn move_to_front(&mut self, value: &T) -> bool {
+        match self.inner.cursor_at_mut(value) {
+            Some(mut c) => {
+                c.move_to_front();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Move a value to the back of the set in O(1).
+    ///
+    /// Returns `false`, if the value is not in the set.
+    pub fn move_to_back(&mut self, value: &T) -> bool {
+        match self.inner.cursor_at_mut(value) {
+            Some(mut c) => {
+                c.move_to_back();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterate the values from front to back
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.inner.iter().map(|(t, _)| t)
+    }
+
+    /// Iterate the values from back to front
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.inner.iter_rev().map(|(t, _)| t)
+    }
+
+    /// Create a cursor over the set at the first value
+    #[inline]
+    pub fn cursor(&self) -> SetCursor<'_, T, S> {
+        SetCursor {
+            inner: self.inner.cursor(),
+        }
+    }
+
+    /// Create a mutable cursor over the set at the first value
+    #[inline]
+    pub fn cursor_mut(&mut self) -> SetCursorMut<'_, T, S> {
+        SetCursorMut {
+            inner: self.inner.cursor_mut(),
+        }
+    }
+
+    /// Create a cursor navigated to the passed value.
+    ///
+    /// Returns [None], if the value is not in the set.
+    #[inline]
+    pub fn cursor_at(&self, value: &T) -> Option<SetCursor<'_, T, S>> {
+        self.inner.cursor_at(value).map(|inner| SetCursor { inner })
+    }
+
+    /// Create a mutable cursor navigated to the passed value.
+    ///
+    /// Returns [None], if the value is not in the set.
+    #[inline]
+    pub fn cursor_at_mut(&mut self, value: &T) -> Option<SetCursorMut<'_, T, S>> {
+        self.inner
+            .cursor_at_mut(value)
+            .map(|inner| SetCursorMut { inner })
+    }
+
+    /// Create a cursor navigated to the value saved via [SetCursorMut::save](SetCursorMut::save).
+    ///
+    /// If no value is currently saved, returns [None].
+    #[inline]
+    pub fn resume(&self) -> Option<SetCursor<'_, T, S>> {
+        self.inner.resume().map(|inner| SetCursor { inner })
+    }
+
+    /// Create a mutable cursor navigated to the value saved via [SetCursorMut::save](SetCursorMut::save).
+    ///
+    /// If no value is currently saved, returns [None].
+    #[inline]
+    pub fn resume_mut(&mut self) -> Option<SetCursorMut<'_, T, S>> {
+        self.inner.resume_mut().map(|inner| SetCursorMut { inner })
+    }
+
+    /// Iterate the values of this set followed by the values of `other`, that are not in this set, each in their
+    /// set's order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::LinkedSet;
+    ///
+    /// let a: LinkedSet<_> = [3, 1, 2].into_iter().collect();
+    /// let b: LinkedSet<_> = [4, 2, 5, 3].into_iter().collect();
+    /// assert_eq!(a.union(&b).copied().collect::<Vec<_>>(), [3, 1, 2, 4, 5]);
+    /// assert_eq!(a.intersection(&b).copied().collect::<Vec<_>>(), [3, 2]);
+    /// assert_eq!(a.difference(&b).copied().collect::<Vec<_>>(), [1]);
+    /// assert_eq!(a.symmetric_difference(&b).copied().collect::<Vec<_>>(), [1, 4, 5]);
+    /// ```
+    pub fn union<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> {
+        self.iter().chain(other.difference(self))
+    }
+
+    /// Iterate the values of this set, that are also in `other`, in this set's order
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |t| other.contains(t))
+    }
+
+    /// Iterate the values of this set, that are not in `other`, in this set's order
+    pub fn difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> {
+        self.iter().filter(move |t| !other.contains(t))
+    }
+
+    /// Iterate the values of this set, that are not in `other`, followed by the values of `other`, that are not in
+    /// this set, each in their set's order
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> impl Iterator<Item = &'a T> {
+        self.difference(other).chain(other.difference(self))
+    }
+}
+
+impl<T, S> Default for LinkedSet<T, S>
+where
+    S: Default,
+{
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: Default::default(),
+        }
+    }
+}
+
+impl<T, S> Clone for LinkedSet<T, S>
+where
+    T: Eq + Hash + Clone + 'static,
+    S: BuildHasher + Default,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T, S> std::fmt::Debug for LinkedSet<T, S>
+where
+    T: Eq + Hash + Clone + std::fmt::Debug + 'static,
+    S: BuildHasher,
+{
+    /// Formats the values in list order
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T, S> FromIterator<T> for LinkedSet<T, S>
+where
+    T: Eq + Hash + Clone + 'static,
+    S: BuildHasher + Default,
+{
+    /// Collect the values in iteration order. Repeated values are moved to the back.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().map(|t| (t, ())).collect(),
+        }
+    }
+}
+
+/// Implements methods common to both [SetCursor] and [SetCursorMut] by delegating to the wrapped map cursor.
+/// `$nav` is the type of values returned by navigation methods.
+macro_rules! impl_set_common {
+    ($nav:ty) => {
+        /// Navigate to the front of the set
+        #[inline]
+        pub fn to_front(&mut self) {
+            self.inner.to_front()
+        }
+
+        /// Navigate to the back of the set
+        #[inline]
+        pub fn to_back(&mut self) {
+            self.inner.to_back()
+        }
+
+        /// Return the value the cursor is at. Only returns [None], if the set is empty.
+        #[inline]
+        pub fn current(&self) -> Option<&T> {
+            self.inner.key()
+        }
+
+        /// Returns the number of values in the set
+        #[inline]
+        pub fn len(&self) -> usize {
+            self.inner.len()
+        }
+
+        /// Returns `true` if the set contains no values
+        #[inline]
+        pub fn is_empty(&self) -> bool {
+            self.inner.is_empty()
+        }
+
+        /// Advance the cursor to the next value and return it.
+        /// Returns [None] and stays in place, if the cursor is at the back.
+        #[inline]
+        #[allow(clippy::should_implement_trait)]
+        pub fn next(&mut self) -> Option<$nav> {
+            self.inner.next().map(|(t, _)| t)
+        }
+
+        /// Move the cursor to the previous value and return it.
+        /// Returns [None] and stays in place, if the cursor is at the front.
+        #[inline]
+        pub fn previous(&mut self) -> Option<$nav> {
+            self.inner.previous().map(|(t, _)| t)
+        }
+
+        /// Navigate to the passed value.
+        /// Returns [None] and stays in place, if the value is not in the set.
+        #[inline]
+        pub fn to_value(&mut self, value: &T) -> Option<$nav> {
+            self.inner.to_key(value).map(|(t, _)| t)
+        }
+
+        /// Navigate to the saved value, if any, and return it
+        #[inline]
+        pub fn resume(&mut self) -> Option<$nav> {
+            self.inner.resume().map(|(t, _)| t)
+        }
+
+        /// Return the next value without moving the cursor
+        #[inline]
+        pub fn peek_next(&self) -> Option<&T> {
+            self.inner.peek_next().map(|(t, _)| t)
+        }
+
+        /// Return the previous value without moving the cursor
+        #[inline]
+        pub fn peek_previous(&self) -> Option<&T> {
+            self.inner.peek_previous().map(|(t, _)| t)
+        }
+
+        /// Advance the cursor towards the back until a value matching `predicate` is found, starting with the
+        /// current value. See [Cursor::seek_forward](Cursor::seek_forward) for details.
+        #[inline]
+        pub fn seek_forward<F>(&mut self, mut predicate: F) -> Result<usize, usize>
+        where
+            F: FnMut(&T) -> bool,
+        {
+            self.inner.seek_forward(|t, _| predicate(t))
+        }
+
+        /// Move the cursor towards the front until a value matching `predicate` is found, starting with the
+        /// current value. See [Cursor::seek_backward](Cursor::seek_backward) for details.
+        #[inline]
+        pub fn seek_backward<F>(&mut self, mut predicate: F) -> Result<usize, usize>
+        where
+            F: FnMut(&T) -> bool,
+        {
+            self.inner.seek_backward(|t, _| predicate(t))
+        }
+
+        /// Advance the cursor `n` values towards the back. See [Cursor::advance_by](Cursor::advance_by) for
+        /// details.
+        #[inline]
+        pub fn advance_by(&mut self, n: usize) -> Result<usize, usize> {
+            self.inner.advance_by(n)
+        }
+
+        /// Move the cursor `n` values towards the front. See [Cursor::retreat_by](Cursor::retreat_by) for details.
+        #[inline]
+        pub fn retreat_by(&mut self, n: usize) -> Result<usize, usize> {
+            self.inner.retreat_by(n)
+        }
+
+        /// Iterate the set towards the back, starting with the current value
+        #[inline]
+        pub fn iter(self) -> impl Iterator<Item = &'a T> {
+            self.inner.iter().map(|(t, _)| t)
+        }
+
+        /// Iterate the set towards the front, starting with the current value
+        #[inline]
+        pub fn iter_rev(self) -> impl Iterator<Item = &'a T> {
+            self.inner.iter_rev().map(|(t, _)| t)
+        }
+    };
+}
+
+/// Enables safe traversal of a [LinkedSet]
+pub struct SetCursor<'a, T, S> {
+    /// Cursor over the underlying map
+    inner: Cursor<'a, T, (), S>,
+}
+
+impl<'a, T, S> SetCursor<'a, T, S>
+where
+    T: Eq + Hash + Clone + 'static,
+    S: BuildHasher,
+{
+    impl_set_common! {&'a T}
+}
+
+impl<'a, T, S> Clone for SetCursor<'a, T, S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Enables safe traversal and mutation of a [LinkedSet]
+///
+/// # Examples
+///
+/// ```
+/// use linked_map::LinkedSet;
+///
+/// let mut set: LinkedSet<_> = (1..=6).collect();
+/// let mut cursor = set.cursor_mut();
+/// while cursor.seek_forward(|t| t % 2 == 0).is_ok() {
+///     cursor.remove_and_advance();
+/// }
+/// cursor.to_front();
+/// cursor.insert_before(0);
+/// assert_eq!(set.iter().copied().collect::<Vec<_>>(), [0, 1, 3, 5]);
+/// ```
+pub struct SetCursorMut<'a, T, S> {
+    /// Cursor over the underlying map
+    inner: CursorMut<'a, T, (), S>,
+}
+
+impl<'a, T, S> SetCursorMut<'a, T, S>
+where
+    T: Eq + Hash + Clone + 'static,
+    S: BuildHasher,
+{
+    impl_set_common! {&T}
+
+    /// Return a read-only cursor at the same position, that borrows this cursor
+    #[inline]
+    pub fn as_cursor(&self) -> SetCursor<'_, T, S> {
+        SetCursor {
+            inner: self.inner.as_cursor(),
+        }
+    }
+
+    /// Return a mutable cursor, that borrows this cursor and shares its position.
+    /// See [CursorMut::reborrow](CursorMut::reborrow) for details.
+    #[inline]
+    pub fn reborrow(&mut self) -> SetCursorMut<'_, T, S> {
+        SetCursorMut {
+            inner: self.inner.reborrow(),
+        }
+    }
+
+    /// Convert into a read-only cursor at the same position
+    #[inline]
+    pub fn into_cursor(self) -> SetCursor<'a, T, S> {
+        SetCursor {
+            inner: self.inner.into_cursor(),
+        }
+    }
+
+    /// Insert a value before the current one.
+    ///
+    /// Returns `true`, if the value was not in the set. Otherwise the value is moved before the current one, unless
+    /// it is the current one. If the set was empty, the cursor navigates to the inserted value.
+    #[inline]
+    pub fn insert_before(&mut self, value: T) -> bool {
+        self.inner.insert_before(value, ()) == InsertOutcome::Inserted
+    }
+
+    /// Insert a value after the current one.
+    ///
+    /// Returns `true`, if the value was not in the set. Otherwise the value is moved after the current one, unless
+    /// it is the current one. If the set was empty, the cursor navigates to the inserted value.
+    #[inline]
+    pub fn insert_after(&mut self, value: T) -> bool {
+        self.inner.insert_after(value, ()) == InsertOutcome::Inserted
+    }
+
+    /// Remove the current value and return it, navigating to the previous value, or the next one at the front.
+    /// Returns [None], if the set is empty.
+    #[inline]
+    pub fn remove(&mut self) -> Option<T> {
+        self.inner.remove().map(|(t, _)| t)
+    }
+
+    /// Remove the current value and return it, navigating to the next value, or the previous one at the back.
+    /// Returns [None], if the set is empty.
+    #[inline]
+    pub fn remove_and_advance(&mut self) -> Option<T> {
+        self.inner.remove_and_advance().map(|(t, _)| t)
+    }
+
+    /// Remove the value after the current one and return it, without moving the cursor
+    #[inline]
+    pub fn remove_next(&mut self) -> Option<T> {
+        self.inner.remove_next().map(|(t, _)| t)
+    }
+
+    /// Remove the value before the current one and return it, without moving the cursor
+    #[inline]
+    pub fn remove_previous(&mut self) -> Option<T> {
+        self.inner.remove_previous().map(|(t, _)| t)
+    }
+
+    /// Move the current value to the front of the set. The cursor stays on the moved value.
+    #[inline]
+    pub fn move_to_front(&mut self) {
+        self.inner.move_to_front()
+    }
+
+    /// Move the current value to the back of the set. The cursor stays on the moved value.
+    #[inline]
+    pub fn move_to_back(&mut self) {
+        self.inner.move_to_back()
+    }
+
+    /// Remember the current position for resuming later. See [CursorMut::save](CursorMut::save) for details.
+    #[inline]
+    pub fn save(&mut self) {
+        self.inner.save()
+    }
+
+    /// Clear any saved position
+    #[inline]
+    pub fn clear_saved(&mut self) {
+        self.inner.clear_saved()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LinkedSet;
+    use crate::{
+        linked_list::tests::{gen_tests, steps, Rng},
+        test_support::compare,
+        InsertOutcome,
+    };
+
+    type SetModel = crate::test_support::Model<u32, ()>;
+
+    /// Return the values of `a`, that are not in `b`, in the order of `a`
+    fn set_difference(a: &SetModel, b: &SetModel) -> Vec<u32> {
+        let values = a.order.iter().filter(|t| !b.values.contains_key(t));
+        values.copied().collect()
+    }
+
+    gen_tests! {test_set_operations}
+    fn test_set_operations(seed: u64) {
+        let mut rng = Rng(seed);
+        let mut sets = [LinkedSet::new(), LinkedSet::new()];
+        let mut models = [SetModel::default(), SetModel::default()];
+
+        for _ in 0..steps(1000) {
+            let i = rng.below(2) as usize;
+            let (set, model) = (&mut sets[i], &mut models[i]);
+            let t = rng.below(24) as u32;
+            match rng.below(8) {
+                0 => assert_eq!(set.insert_front(t), model.prepend(t, ()).is_none()),
+                1 => assert_eq!(set.insert_back(t), model.append(t, ()).is_none()),
+                2 => assert_eq!(set.remove(&t), model.remove(t).is_some()),
+                3 => {
+                    let present = model.values.contains_key(&t);
+                    assert_eq!(set.move_to_front(&t), present);
+                    if present {
+                        model.move_to(t, |_| 0);
+                    }
+                }
+                4 => {
+                    let present = model.values.contains_key(&t);
+                    assert_eq!(set.move_to_back(&t), present);
+                    if present {
+                        model.move_to(t, |m| m.order.len());
+                    }
+                }
+                5 | 6 => {
+                    let Some(mut c) = set.cursor_at_mut(&t) else {
+                        continue;
+                    };
+                    model.cursor = model.position(t).unwrap();
+                    let u = rng.below(24) as u32;
+                    match rng.below(3) {
+                        0 => assert_eq!(
+                            c.insert_before(u),
+                            model.insert_at_cursor(u, (), false, true) == InsertOutcome::Inserted
+                        ),
+                        1 => assert_eq!(
+                            c.insert_after(u),
+                            model.insert_at_cursor(u, (), true, true) == InsertOutcome::Inserted
+                        ),
+                        _ => assert_eq!(
+                            c.remove_and_advance(),
+                            model.remove_and_advance().map(|(t, _)| t)
+                        ),
+                    }
+                    assert_eq!(c.current().copied(), model.cursor_key());
+                }
+                _ => assert_eq!(set.contains(&t), model.values.contains_key(&t)),
+            }
+            compare(set.as_map(), model);
+
+            for (a, b, ma, mb) in [
+                (&sets[0], &sets[1], &models[0], &models[1]),
+                (&sets[1], &sets[0], &models[1], &models[0]),
+            ] {
+                let union: Vec<_> = ma
+                    .order
+                    .iter()
+                    .copied()
+                    .chain(set_difference(mb, ma))
+                    .collect();
+                assert!(a.union(b).copied().eq(union));
+
+                let intersection = ma.order.iter().filter(|t| mb.values.contains_key(t));
+                assert!(a.intersection(b).eq(intersection));
+
+                assert!(a.difference(b).copied().eq(set_difference(ma, mb)));
+
+                let symmetric = set_difference(ma, mb)
+                    .into_iter()
+                    .chain(set_difference(mb, ma));
+                assert!(a.symmetric_difference(b).copied().eq(symmetric));
+            }
+        }
+    }
+}
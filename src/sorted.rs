@@ -0,0 +1,206 @@
+use std::{
+    cmp::Ordering,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{map::DefaultHashBuilder, Cursor, LinkedMap, ScanFrom};
+
+/// [LinkedMap] wrapper, that keeps its entries sorted by a comparator on every insert, while still providing O(1)
+/// key lookup.
+///
+/// The comparator receives two key-value pairs to compare. Only read-only access to the underlying [LinkedMap] is
+/// provided, as reordering the entries or modifying the values could break the sorting invariant.
+pub struct SortedLinkedMap<K, V, C, S = DefaultHashBuilder> {
+    /// Underlying map storing the sorted entries
+    inner: LinkedMap<K, V, S>,
+
+    /// Comparator the entries are sorted by
+    cmp: C,
+}
+
+impl<K, V, C> SortedLinkedMap<K, V, C, DefaultHashBuilder> {
+    /// Create a new empty [SortedLinkedMap] sorted by `cmp`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::SortedLinkedMap;
+    ///
+    /// let mut map = SortedLinkedMap::new(|_: &&str, a: &u32, _: &&str, b: &u32| a.cmp(b));
+    /// map.insert("late", 30);
+    /// map.insert("early", 10);
+    /// map.insert("middle", 20);
+    /// assert_eq!(map.iter().map(|(k, _)| *k).collect::<Vec<_>>(), ["early", "middle", "late"]);
+    /// ```
+    #[inline]
+    pub fn new(cmp: C) -> Self {
+        Self::with_hasher(cmp, Default::default())
+    }
+}
+
+impl<K, V, C, S> SortedLinkedMap<K, V, C, S> {
+    /// Create a new empty [SortedLinkedMap] sorted by `cmp`, which will use the given hash builder to hash keys
+    #[inline]
+    pub const fn with_hasher(cmp: C, hash_builder: S) -> Self {
+        Self {
+            inner: LinkedMap::with_hasher(hash_builder),
+            cmp,
+        }
+    }
+
+    /// Returns the number of elements in the map
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Returns `true` if the map contains no elements
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Return a reference to the underlying [LinkedMap]
+    #[inline]
+    pub fn as_map(&self) -> &LinkedMap<K, V, S> {
+        &self.inner
+    }
+
+    /// Consume the wrapper and return the underlying [LinkedMap]
+    #[inline]
+    pub fn into_inner(self) -> LinkedMap<K, V, S> {
+        self.inner
+    }
+}
+
+impl<K, V, C, S> SortedLinkedMap<K, V, C, S>
+where
+    K: Eq + Hash + Clone + 'static,
+    V: 'static,
+    C: FnMut(&K, &V, &K, &V) -> Ordering,
+    S: BuildHasher,
+{
+    /// Wrap an existing [LinkedMap], sorting its entries by `cmp` first
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::{LinkedMap, SortedLinkedMap};
+    ///
+    /// let map: LinkedMap<_, _> = [(3, ()), (1, ()), (2, ())].into_iter().collect();
+    /// let sorted = SortedLinkedMap::from_map(map, |a: &i32, _: &(), b: &i32, _: &()| a.cmp(b));
+    /// assert_eq!(sorted.iter().map(|(k, _)| *k).collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    pub fn from_map(mut inner: LinkedMap<K, V, S>, mut cmp: C) -> Self {
+        if !inner.is_sorted_by(&mut cmp) {
+            inner.sort_by(&mut cmp);
+        }
+        Self { inner, cmp }
+    }
+
+    /// Insert a key-value pair at its sorted position, scanning from the end of the list.
+    ///
+    /// If the map did have this key present, the value is updated, the entry is moved to its new sorted position
+    /// and the old value is returned.
+    #[inline]
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        self.insert_with_hint(k, v, ScanFrom::Tail)
+    }
+
+    /// Insert a key-value pair at its sorted position, scanning from the node selected by `hint`.
+    ///
+    /// See [LinkedMap::insert_sorted_by](LinkedMap::insert_sorted_by) for details.
+    #[inline]
+    pub fn insert_with_hint(&mut self, k: K, v: V, hint: ScanFrom<'_, K>) -> Option<V> {
+        self.inner.insert_sorted_by(k, v, hint, &mut self.cmp)
+    }
+
+    /// Remove a key from the map, returning its value, if the key was previously in the map
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use linked_map::SortedLinkedMap;
+    ///
+    /// let mut map = SortedLinkedMap::new(|a: &i32, _: &(), b: &i32, _: &()| a.cmp(b));
+    /// map.insert(1, ());
+    /// assert_eq!(map.remove(&1), Some(()));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        self.inner.remove(k)
+    }
+
+    /// Return a reference to the value of the key, if any
+    pub fn get(&self, k: &K) -> Option<&V> {
+        self.inner.get(k)
+    }
+
+    /// Returns `true`, if the map contains the key
+    pub fn contains_key(&self, k: &K) -> bool {
+        self.inner.contains_key(k)
+    }
+
+    /// Iterate the entries in sorted order
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter()
+    }
+
+    /// Iterate the entries in reverse sorted order
+    #[inline]
+    pub fn iter_rev(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.inner.iter_rev()
+    }
+
+    /// Create a cursor over the map set to the first entry
+    #[inline]
+    pub fn cursor(&self) -> Cursor<'_, K, V, S> {
+        self.inner.cursor()
+    }
+
+    /// Create a cursor navigated to the passed key.
+    ///
+    /// Returns [None], if the key is not in the map.
+    #[inline]
+    pub fn cursor_at(&self, k: &K) -> Option<Cursor<'_, K, V, S>> {
+        self.inner.cursor_at(k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SortedLinkedMap;
+    use crate::{
+        linked_list::tests::{compare_sorted, gen_tests, steps, Rng},
+        ScanFrom,
+    };
+    use std::collections::HashMap;
+
+    gen_tests! {test_sorted_linked_map}
+    fn test_sorted_linked_map(seed: u64) {
+        let mut rng = Rng(seed);
+        let mut map = SortedLinkedMap::new(|_: &u32, a: &u32, _: &u32, b: &u32| a.cmp(b));
+        let mut values = HashMap::new();
+
+        for _ in 0..steps(2000) {
+            let k = rng.below(32) as u32;
+            let v = rng.below(8) as u32;
+            let hint_key = rng.below(40) as u32;
+            match rng.below(4) {
+                0 => assert_eq!(map.insert(k, v), values.insert(k, v)),
+                1 => assert_eq!(
+                    map.insert_with_hint(k, v, ScanFrom::Key(&hint_key)),
+                    values.insert(k, v)
+                ),
+                2 => assert_eq!(
+                    map.insert_with_hint(k, v, ScanFrom::Head),
+                    values.insert(k, v)
+                ),
+                _ => assert_eq!(map.remove(&k), values.remove(&k)),
+            }
+            assert_eq!(map.get(&k), values.get(&k));
+            compare_sorted(map.as_map(), &values);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+//! Reference model shared by the unit tests and the fuzz targets. Not part of the public API.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+    hash::{BuildHasher, Hash},
+};
+
+use crate::{CursorMut, InsertOutcome, LinkedMap};
+
+/// Reference implementation of the map, a cursor position and the saved node
+pub struct Model<K, V> {
+    pub order: VecDeque<K>,
+    pub values: HashMap<K, V>,
+    pub cursor: usize,
+    pub saved: Option<K>,
+}
+
+impl<K, V> Default for Model<K, V> {
+    fn default() -> Self {
+        Self {
+            order: Default::default(),
+            values: Default::default(),
+            cursor: 0,
+            saved: None,
+        }
+    }
+}
+
+impl<K, V> Model<K, V>
+where
+    K: Copy + Eq + Hash,
+    V: Copy,
+{
+    pub fn position(&self, k: K) -> Option<usize> {
+        self.order.iter().position(|o| *o == k)
+    }
+
+    pub fn cursor_key(&self) -> Option<K> {
+        self.order.get(self.cursor).copied()
+    }
+
+    pub fn remove(&mut self, k: K) -> Option<V> {
+        let i = self.position(k)?;
+        self.order.remove(i);
+        if self.saved == Some(k) {
+            self.saved = None;
+        }
+        self.values.remove(&k)
+    }
+
+    pub fn prepend(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(i) = self.position(k) {
+            self.order.remove(i);
+        }
+        self.order.push_front(k);
+        self.values.insert(k, v)
+    }
+
+    pub fn append(&mut self, k: K, v: V) -> Option<V> {
+        if let Some(i) = self.position(k) {
+            self.order.remove(i);
+        }
+        self.order.push_back(k);
+        self.values.insert(k, v)
+    }
+
+    /// Move `k` to index `to` of the order, as computed after removing it
+    pub fn move_to(&mut self, k: K, to: impl FnOnce(&Self) -> usize) {
+        let i = self.position(k).unwrap();
+        self.order.remove(i);
+        let to = to(self);
+        self.order.insert(to, k);
+    }
+
+    /// Insert next to the cursor. Existing keys are moved there, only if `move_existing` is set.
+    pub fn insert_at_cursor(
+        &mut self,
+        k: K,
+        v: V,
+        after: bool,
+        move_existing: bool,
+    ) -> InsertOutcome<V> {
+        let current = match self.cursor_key() {
+            Some(current) => current,
+            None => {
+                self.append(k, v);
+                self.cursor = 0;
+                return InsertOutcome::Inserted;
+            }
+        };
+        if let Some(old_value) = self.values.get(&k).copied() {
+            self.values.insert(k, v);
+            if current == k || !move_existing {
+                return InsertOutcome::UpdatedInPlace { old_value };
+            }
+            self.move_to(k, |m| m.position(current).unwrap() + after as usize);
+            self.cursor = self.position(current).unwrap();
+            return InsertOutcome::Moved { old_value };
+        }
+
+        let anchor = self.cursor + after as usize;
+        self.order.insert(anchor, k);
+        self.values.insert(k, v);
+        self.cursor = self.position(current).unwrap();
+        InsertOutcome::Inserted
+    }
+
+    /// Remove the key at the cursor and place the cursor at the next node, if any, or the new tail
+    pub fn remove_and_advance(&mut self) -> Option<(K, V)> {
+        let k = self.cursor_key()?;
+        let v = self.remove(k).unwrap();
+        if self.cursor == self.order.len() {
+            self.cursor = self.cursor.saturating_sub(1);
+        }
+        Some((k, v))
+    }
+
+    /// Remove the key at the cursor and place the cursor at the previous node, or the new head
+    pub fn remove_and_retreat(&mut self) -> Option<(K, V)> {
+        let k = self.cursor_key()?;
+        let v = self.remove(k).unwrap();
+        self.cursor = self.cursor.saturating_sub(1);
+        Some((k, v))
+    }
+
+    pub fn entries(&self) -> Vec<(K, V)> {
+        self.order.iter().map(|k| (*k, self.values[k])).collect()
+    }
+}
+
+/// Assert the structural invariants of the map and that it matches the model in both iteration directions
+pub fn compare<K, V, S>(map: &LinkedMap<K, V, S>, model: &Model<K, V>)
+where
+    K: Copy + Eq + Hash + Debug + 'static,
+    V: Copy + PartialEq + Debug + 'static,
+    S: BuildHasher,
+{
+    if let Err(e) = map.check_invariants() {
+        panic!("invariant violated: {}", e);
+    }
+
+    let expected = model.entries();
+    let forward: Vec<_> = map.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(forward, expected);
+
+    let mut backward: Vec<_> = map.iter_rev().map(|(k, v)| (*k, *v)).collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+
+    assert_eq!(map.len(), expected.len());
+    for (k, v) in &expected {
+        assert_eq!(map.get(k), Some(v));
+    }
+    assert_eq!(map.resume().and_then(|c| c.key().copied()), model.saved);
+}
+
+/// Assert the position of a mutable cursor and the map it borrows match the model in both iteration directions
+pub fn compare_cursor<K, V, S>(c: &CursorMut<'_, K, V, S>, model: &Model<K, V>)
+where
+    K: Copy + Eq + Hash + Debug + 'static,
+    V: Copy + PartialEq + Debug + 'static,
+    S: BuildHasher,
+{
+    assert_eq!(c.key().copied(), model.cursor_key());
+    assert_eq!(c.len(), model.order.len());
+
+    let expected = model.entries();
+    let mut front = c.as_cursor();
+    front.to_front();
+    let forward: Vec<_> = front.iter().map(|(k, v)| (*k, *v)).collect();
+    assert_eq!(forward, expected);
+
+    let mut back = c.as_cursor();
+    back.to_back();
+    let mut backward: Vec<_> = back.iter_rev().map(|(k, v)| (*k, *v)).collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+}